@@ -1,6 +1,7 @@
 // Advent of Code 2017 Library
 // This library provides common utilities for solving AOC puzzles
 
+pub mod runner;
 pub mod solutions;
 pub mod utils;
 
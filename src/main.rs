@@ -1,371 +1,833 @@
-// Advent of Code 2017 - Solution Runner
+// Advent of Code - Solution Runner
 // Runs all implemented solutions for testing and verification
 
-use advent_of_code_2017::solutions;
+use advent_of_code_2017::runner::bench::{self, format_micros, Baseline, Stats};
+use advent_of_code_2017::runner::cache::{self, AnswerCache, CacheKey, CachedAnswer};
+use advent_of_code_2017::runner::memory::{self, CountingAllocator};
+use advent_of_code_2017::runner::repl::{Reply, Session};
+use advent_of_code_2017::runner::report::{OutputFormat, PartRecord, Reporter};
+use advent_of_code_2017::runner::verify::AnswerManifest;
+use advent_of_code_2017::runner::watch::{self, Answers, Snapshot};
+use advent_of_code_2017::runner::{examples, parallel, watchdog, DaySelection};
+use advent_of_code_2017::solutions::{self, Solution, Year};
 use advent_of_code_2017::utils::input;
-use anyhow::Result;
-use std::env;
-use std::time::Instant;
-
-const MAX_DAY: u32 = 25;
-
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command as ProcessCommand, Stdio};
+use std::time::{Duration, Instant};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Advent of Code solution runner", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(help = "Days to run, e.g. 7 or 3-7,12 (default: all days)")]
+    days: Option<DaySelection>,
+
+    #[arg(
+        short,
+        long,
+        help = "Puzzle year (default: the latest year with solutions)"
+    )]
+    year: Option<u16>,
+
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u32).range(1..=2),
+        help = "Only run this part (1 or 2)"
+    )]
+    part: Option<u32>,
+
+    #[arg(
+        short,
+        long,
+        help = "Read input from this file instead of the embedded input ('-' for stdin, .gz allowed)"
+    )]
+    input: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Read inputs from DIR/dayNN/input.txt[.gz] instead of the embedded ones"
+    )]
+    input_dir: Option<PathBuf>,
+
+    #[arg(short, long, help = "Print answers only, one per line")]
+    quiet: bool,
+
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        help = "Output format"
+    )]
+    format: OutputFormat,
+
+    #[arg(long, help = "Check answers against the expected-answers manifest")]
+    verify: bool,
+
+    #[arg(
+        long,
+        help = "Expected-answers manifest used by --verify (default: the embedded one)"
+    )]
+    answers: Option<PathBuf>,
+
+    #[arg(
+        short,
+        long,
+        default_value_t = 1,
+        help = "Run days and parts on this many threads (0 = one per CPU)"
+    )]
+    jobs: usize,
+
+    #[arg(
+        short,
+        long,
+        value_name = "SECS",
+        value_parser = parse_timeout,
+        help = "Give up on a part after this many seconds and move on"
+    )]
+    timeout: Option<Duration>,
+
+    #[arg(
+        short,
+        long,
+        help = "Report peak and total allocated bytes and allocation counts per part"
+    )]
+    memory: bool,
+
+    #[arg(long, help = "Recompute every part instead of reusing cached answers")]
+    no_cache: bool,
+}
 
-    if args.len() > 1 {
-        let day: u32 = args[1]
-            .parse()
-            .map_err(|_| anyhow::anyhow!("Invalid day number"))?;
-        run_specific_day(day)?;
-    } else {
-        run_all_solutions()?;
+/// Parse a positive number of seconds, fractions allowed
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    match value.parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(format!("'{}' is not a positive number of seconds", value)),
     }
+}
 
-    Ok(())
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Time each part repeatedly and report summary statistics
+    Bench(BenchArgs),
+    /// Re-run a day's examples and input whenever its files change
+    Watch(WatchArgs),
+    /// Check every solver against the examples from its puzzle text
+    Examples(ExamplesArgs),
+    /// Interactively load a day, edit its input and run or inspect it
+    Repl(ReplArgs),
+    /// Manage the answer cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
 }
 
-fn run_all_solutions() -> Result<()> {
-    println!("🎄 Advent of Code 2017 - Running All Solutions 🎄\n");
+#[derive(clap::Args, Debug)]
+struct WatchArgs {
+    #[arg(help = "Day to watch")]
+    day: u32,
+
+    #[arg(
+        short,
+        long,
+        help = "Puzzle year (default: the latest year with solutions)"
+    )]
+    year: Option<u16>,
+
+    #[arg(
+        long,
+        default_value_os_t = aoc_layout::repo_root(),
+        help = "Repository root (default: the checkout this runner was built from)"
+    )]
+    root: PathBuf,
+
+    #[arg(long, default_value_t = 500, help = "Polling interval in milliseconds")]
+    interval: u64,
+}
 
-    for day in 1..=MAX_DAY {
-        run_day(day)?;
-    }
+#[derive(clap::Args, Debug)]
+struct ExamplesArgs {
+    #[arg(help = "Days to check, e.g. 7 or 3-7,12 (default: all days)")]
+    days: Option<DaySelection>,
+
+    #[arg(
+        short,
+        long,
+        help = "Puzzle year (default: the latest year with solutions)"
+    )]
+    year: Option<u16>,
+
+    #[arg(
+        long,
+        default_value_os_t = aoc_layout::repo_root(),
+        help = "Repository root (default: the checkout this runner was built from)"
+    )]
+    root: PathBuf,
+
+    #[arg(
+        long,
+        help = "Extract examples.txt from each day's puzzle.txt instead of running them"
+    )]
+    extract: bool,
+
+    #[arg(
+        long,
+        requires = "extract",
+        help = "Overwrite existing examples.txt files"
+    )]
+    force: bool,
+
+    #[arg(
+        short,
+        long,
+        value_name = "SECS",
+        default_value = "10",
+        value_parser = parse_timeout,
+        help = "Give up on an example after this many seconds"
+    )]
+    timeout: Duration,
+}
 
-    println!("\n✅ All solutions completed!");
-    Ok(())
+#[derive(clap::Args, Debug)]
+struct ReplArgs {
+    #[arg(
+        short,
+        long,
+        help = "Puzzle year (default: the latest year with solutions)"
+    )]
+    year: Option<u16>,
+
+    #[arg(
+        long,
+        default_value_os_t = aoc_layout::repo_root(),
+        help = "Repository root, for examples.txt (default: the checkout this runner was built from)"
+    )]
+    root: PathBuf,
 }
 
-fn run_specific_day(day: u32) -> Result<()> {
-    println!("🎄 Advent of Code 2017 - Running Day {} 🎄\n", day);
-    run_day(day)?;
-    Ok(())
+#[derive(Subcommand, Debug)]
+enum CacheAction {
+    /// Delete all cached answers
+    Clear,
+    /// Show where the cache lives and how many answers it holds
+    Info,
 }
 
-fn run_day(day: u32) -> Result<()> {
-    // Check if day solution exists by trying to read the module file
-    let mod_path = format!("src/solutions/day{:02}/mod.rs", day);
-    if !std::path::Path::new(&mod_path).exists() {
-        println!("❌ Day {} not implemented yet", day);
-        return Ok(());
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    #[arg(help = "Days to benchmark, e.g. 7 or 3-7,12 (default: all days)")]
+    days: Option<DaySelection>,
+
+    #[arg(
+        short,
+        long,
+        help = "Puzzle year (default: the latest year with solutions)"
+    )]
+    year: Option<u16>,
+
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u32).range(1..=2),
+        help = "Only benchmark this part (1 or 2)"
+    )]
+    part: Option<u32>,
+
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 10,
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "Timed runs per part"
+    )]
+    iterations: u32,
+
+    #[arg(
+        short,
+        long,
+        default_value_t = 1,
+        help = "Untimed warm-up runs per part"
+    )]
+    warmup: u32,
+
+    #[arg(
+        long,
+        help = "Read inputs from DIR/dayNN/input.txt[.gz] instead of the embedded ones"
+    )]
+    input_dir: Option<PathBuf>,
+
+    #[arg(long, help = "Compare medians against this baseline file")]
+    baseline: Option<PathBuf>,
+
+    #[arg(long, help = "Write the medians of this run to a baseline file")]
+    save_baseline: Option<PathBuf>,
+
+    #[arg(
+        long,
+        default_value_t = 10.0,
+        help = "Percentage slowdown against the baseline reported as a regression"
+    )]
+    threshold: f64,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match &args.command {
+        Some(Command::Bench(bench_args)) => return run_bench(bench_args),
+        Some(Command::Watch(watch_args)) => return run_watch(watch_args),
+        Some(Command::Examples(examples_args)) => return run_examples(examples_args),
+        Some(Command::Repl(repl_args)) => return run_repl(repl_args),
+        Some(Command::Cache { action }) => return run_cache(action),
+        None => {}
     }
 
-    // Read the title from the first line of the module file
-    let fallback_title = format!("Day {}", day);
-    let title = std::fs::read_to_string(&mod_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read module file for day {}: {}", day, e))?
-        .lines()
-        .next()
-        .map(|line| line.trim_start_matches("// "))
-        .unwrap_or(&fallback_title)
-        .to_string();
-
-    let formatted_title = format!("📅 {}", title);
-
-    // Call appropriate solver based on day (hardcoded for return type compatibility)
-    match day {
-        1 => run_day_u32(
-            &formatted_title,
-            solutions::day01::solve_part1,
-            solutions::day01::solve_part2,
-            day,
-        ),
-        2 => run_day_u32(
-            &formatted_title,
-            solutions::day02::solve_part1,
-            solutions::day02::solve_part2,
-            day,
-        ),
-        3 => run_day_i32(
-            &formatted_title,
-            solutions::day03::solve_part1,
-            solutions::day03::solve_part2,
-            day,
-        ),
-        4 => run_day_usize(
-            &formatted_title,
-            solutions::day04::solve_part1,
-            solutions::day04::solve_part2,
-            day,
-        ),
-        5 => run_day_u32(
-            &formatted_title,
-            solutions::day05::solve_part1,
-            solutions::day05::solve_part2,
-            day,
-        ),
-        6 => run_day_usize(
-            &formatted_title,
-            solutions::day06::solve_part1,
-            solutions::day06::solve_part2,
-            day,
-        ),
-        7 => run_day_string_i32(
-            &formatted_title,
-            solutions::day07::solve_part1,
-            solutions::day07::solve_part2,
-            day,
-        ),
-        8 => run_day_i32(
-            &formatted_title,
-            solutions::day08::solve_part1,
-            solutions::day08::solve_part2,
-            day,
-        ),
-        9 => run_day_i32(
-            &formatted_title,
-            solutions::day09::solve_part1,
-            solutions::day09::solve_part2,
-            day,
-        ),
-        10 => run_day_u32_string(
-            &formatted_title,
-            solutions::day10::solve_part1,
-            solutions::day10::solve_part2,
-            day,
-        ),
-        11 => run_day_i32(
-            &formatted_title,
-            solutions::day11::solve_part1,
-            solutions::day11::solve_part2,
-            day,
-        ),
-        12 => run_day_usize(
-            &formatted_title,
-            solutions::day12::solve_part1,
-            solutions::day12::solve_part2,
-            day,
-        ),
-        13 => run_day_usize(
-            &formatted_title,
-            solutions::day13::solve_part1,
-            solutions::day13::solve_part2,
-            day,
-        ),
-        14 => run_day_u32(
-            &formatted_title,
-            solutions::day14::solve_part1,
-            solutions::day14::solve_part2,
-            day,
-        ),
-        15 => run_day_u32(
-            &formatted_title,
-            solutions::day15::solve_part1,
-            solutions::day15::solve_part2,
-            day,
-        ),
-        16 => run_day_string(
-            &formatted_title,
-            solutions::day16::solve_part1,
-            solutions::day16::solve_part2,
-            day,
-        ),
-        17 => run_day_string(
-            &formatted_title,
-            solutions::day17::solve_part1,
-            solutions::day17::solve_part2,
-            day,
-        ),
-        18 => run_day_string(
-            &formatted_title,
-            solutions::day18::solve_part1,
-            solutions::day18::solve_part2,
-            day,
-        ),
-        19 => run_day_string(
-            &formatted_title,
-            solutions::day19::solve_part1,
-            solutions::day19::solve_part2,
-            day,
-        ),
-        20 => run_day_string(
-            &formatted_title,
-            solutions::day20::solve_part1,
-            solutions::day20::solve_part2,
-            day,
-        ),
-        21 => run_day_string(
-            &formatted_title,
-            solutions::day21::solve_part1,
-            solutions::day21::solve_part2,
-            day,
-        ),
-        22 => run_day_i32(
-            &formatted_title,
-            solutions::day22::solve_part1,
-            solutions::day22::solve_part2,
-            day,
-        ),
-        23 => run_day_i32(
-            &formatted_title,
-            solutions::day23::solve_part1,
-            solutions::day23::solve_part2,
-            day,
-        ),
-        24 => run_day_i32(
-            &formatted_title,
-            solutions::day24::solve_part1,
-            solutions::day24::solve_part2,
-            day,
-        ),
-        25 => run_day_i32(
-            &formatted_title,
-            solutions::day25::solve_part1,
-            solutions::day25::solve_part2,
-            day,
-        ),
-        _ => {
-            println!("❌ Day {} not implemented yet", day);
-            Ok(())
-        }
+    let year = select_year(args.year)?;
+    let selection = args.days.clone().unwrap_or_else(DaySelection::all);
+    if args.input.is_some() && selection.days().len() != 1 {
+        anyhow::bail!("--input can only be used with a single day");
     }
-}
 
-fn run_day_u32(
-    title: &str,
-    solve1: fn(&str) -> u32,
-    solve2: fn(&str) -> u32,
-    day_num: u32,
-) -> Result<()> {
-    println!("{}", title);
-    let input_path = format!("src/solutions/day{:02}/input.txt", day_num);
-    let input = input::read_input(&input_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read input for day {}: {}", day_num, e))?;
+    let manifest = if args.verify {
+        Some(match &args.answers {
+            Some(path) => AnswerManifest::load(path)?,
+            None => AnswerManifest::embedded(year.year).unwrap_or_default(),
+        })
+    } else {
+        None
+    };
 
-    let start = Instant::now();
-    let p1 = solve1(&input);
-    let d1 = start.elapsed();
-    println!("  Part 1: {} ({}µs)", p1, d1.as_micros());
+    if args.memory {
+        memory::enable();
+    }
 
-    let start = Instant::now();
-    let p2 = solve2(&input);
-    let d2 = start.elapsed();
-    println!("  Part 2: {} ({}µs)", p2, d2.as_micros());
+    let reporter = Reporter::new(args.format, args.quiet);
+    match selection.days() {
+        [day] => reporter.begin(&format!(
+            "Advent of Code {} - Running Day {}",
+            year.year, day
+        )),
+        _ if args.days.is_none() => reporter.begin(&format!(
+            "Advent of Code {} - Running All Solutions",
+            year.year
+        )),
+        _ => reporter.begin(&format!(
+            "Advent of Code {} - Running Selected Solutions",
+            year.year
+        )),
+    }
 
-    println!("  ✅ Day {} completed!\n", day_num);
-    Ok(())
-}
+    // Inputs are loaded up front so that worker threads only run solvers
+    let mut plans = Vec::new();
+    for &day in selection.days() {
+        let plan = match year.get(day) {
+            Some(solution) => {
+                let input = load_input(
+                    solution,
+                    year.year,
+                    args.input.as_ref(),
+                    args.input_dir.as_ref(),
+                )?;
+                let hash = cache::input_hash(&input);
+                Some((solution, input, hash))
+            }
+            None => None,
+        };
+        plans.push((day, plan));
+    }
 
-fn run_day_i32(
-    title: &str,
-    solve1: fn(&str) -> i32,
-    solve2: fn(&str) -> i32,
-    day_num: u32,
-) -> Result<()> {
-    println!("{}", title);
-    let input_path = format!("src/solutions/day{:02}/input.txt", day_num);
-    let input = input::read_input(&input_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read input for day {}: {}", day_num, e))?;
+    // Memory statistics are never cached, so measuring them bypasses the cache;
+    // verifying must actually run the solvers, so it bypasses it too
+    let cache_path = AnswerCache::default_path();
+    let mut answer_cache = if args.no_cache || args.memory || args.verify {
+        None
+    } else {
+        Some(AnswerCache::load(&cache_path))
+    };
+    let cache_view = answer_cache.as_ref();
+    let mut fresh = Vec::new();
+
+    let parts: Vec<u32> = [1, 2]
+        .into_iter()
+        .filter(|&part| args.part.is_none_or(|p| p == part))
+        .collect();
+    let tasks: Vec<(&'static dyn Solution, &str, &str, u32)> = plans
+        .iter()
+        .filter_map(|(_, plan)| plan.as_ref())
+        .flat_map(|(solution, input, hash)| {
+            parts
+                .iter()
+                .map(|&part| (*solution, input.as_str(), hash.as_str(), part))
+        })
+        .collect();
+
+    let mut failures = 0;
+    let mut cpu_time = Duration::ZERO;
+    let mut pending = plans.iter().peekable();
+    let mut records = Vec::new();
+    let started = Instant::now();
+
+    parallel::run_ordered(
+        parallel::resolve_jobs(args.jobs),
+        tasks,
+        |(solution, input, hash, part)| {
+            let key = CacheKey::new(year.year, solution, part, hash);
+            let record = match cache_view.and_then(|c| c.get(&key)) {
+                Some(hit) => PartRecord::new(
+                    solution.day(),
+                    part,
+                    solution.title(),
+                    Ok(hit.answer.clone()),
+                    Duration::from_micros(hit.time_us),
+                )
+                .from_cache(),
+                None => run_part(solution, part, input, args.timeout, args.memory),
+            };
+            (key, record)
+        },
+        |_, (key, record)| {
+            while let Some((day, None)) = pending.peek() {
+                reporter.missing(*day);
+                pending.next();
+            }
+
+            if !record.cached {
+                cpu_time += Duration::from_micros(record.time_us);
+                if let Some(answer) = &record.answer {
+                    let time_us = record.time_us;
+                    fresh.push((
+                        key,
+                        CachedAnswer {
+                            answer: answer.clone(),
+                            time_us,
+                        },
+                    ));
+                }
+            }
+            let expected = manifest
+                .as_ref()
+                .and_then(|m| m.expected(record.day, record.part));
+            records.push(record.with_expected(expected));
+
+            if records.len() == parts.len() {
+                if let Some((day, Some((solution, _, _)))) = pending.next() {
+                    failures += records.iter().filter(|r| !r.is_ok()).count();
+                    reporter.day(*day, solution.title(), &records);
+                }
+                records.clear();
+            }
+        },
+    );
+    for (day, _) in pending {
+        reporter.missing(*day);
+    }
 
-    let start = Instant::now();
-    let p1 = solve1(&input);
-    let d1 = start.elapsed();
-    println!("  Part 1: {} ({}µs)", p1, d1.as_micros());
+    if let Some(answer_cache) = answer_cache.as_mut() {
+        for (key, answer) in fresh {
+            answer_cache.insert(&key, answer);
+        }
+        answer_cache.save(&cache_path)?;
+    }
 
-    let start = Instant::now();
-    let p2 = solve2(&input);
-    let d2 = start.elapsed();
-    println!("  Part 2: {} ({}µs)", p2, d2.as_micros());
+    if selection.days().len() > 1 {
+        reporter.timing(started.elapsed(), cpu_time);
+    }
 
-    println!("  ✅ Day {} completed!\n", day_num);
+    if failures > 0 {
+        anyhow::bail!("{} part(s) failed", failures);
+    }
+
+    if selection.days().len() > 1 {
+        reporter.finish();
+    }
     Ok(())
 }
 
-fn run_day_usize(
-    title: &str,
-    solve1: fn(&str) -> usize,
-    solve2: fn(&str) -> usize,
-    day_num: u32,
-) -> Result<()> {
-    println!("{}", title);
-    let input_path = format!("src/solutions/day{:02}/input.txt", day_num);
-    let input = input::read_input(&input_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read input for day {}: {}", day_num, e))?;
+/// Rebuild and re-run a day every time a file in its directory changes
+fn run_watch(args: &WatchArgs) -> Result<()> {
+    let year = args.year.unwrap_or_else(solutions::latest_year);
+    let dir = aoc_layout::day_dir(&args.root, year, args.day);
+    if !dir.is_dir() {
+        anyhow::bail!("{} does not exist", dir.display());
+    }
+    let interval = Duration::from_millis(args.interval);
+
+    println!("👀 Watching {} (Ctrl-C to stop)", dir.display());
+    let mut snapshot = Snapshot::take(&dir);
+    let mut previous: Option<Answers> = None;
+    loop {
+        let answers = run_watched_day(args, year, previous.as_ref())?;
+        if !answers.is_empty() {
+            previous = Some(answers);
+        }
 
-    let start = Instant::now();
-    let p1 = solve1(&input);
-    let d1 = start.elapsed();
-    println!("  Part 1: {} ({}µs)", p1, d1.as_micros());
+        loop {
+            std::thread::sleep(interval);
+            let changed = Snapshot::take(&dir).changed_since(&snapshot);
+            if !changed.is_empty() {
+                // Give editors a moment to finish writing before rebuilding
+                std::thread::sleep(interval);
+                snapshot = Snapshot::take(&dir);
+                let names: Vec<String> = changed
+                    .iter()
+                    .filter_map(|path| path.file_name())
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .collect();
+                println!("\n🔄 Changed: {}", names.join(", "));
+                break;
+            }
+        }
+    }
+}
 
-    let start = Instant::now();
-    let p2 = solve2(&input);
-    let d2 = start.elapsed();
-    println!("  Part 2: {} ({}µs)", p2, d2.as_micros());
+/// Run the day's example tests, then the day itself, printing how answers changed
+fn run_watched_day(args: &WatchArgs, year: u16, previous: Option<&Answers>) -> Result<Answers> {
+    let tests = format!(
+        "{}::{}::tests",
+        aoc_layout::year_module(year),
+        aoc_layout::day_module(args.day)
+    );
+    println!("🧪 Examples");
+    let status = ProcessCommand::new("cargo")
+        .current_dir(&args.root)
+        .args(["test", "--release", "--quiet", "--lib", "--"])
+        .args([tests.as_str(), "--skip", "input"])
+        .status()
+        .context("Failed to run cargo test")?;
+    if !status.success() {
+        println!("  ❌ Example tests failed");
+    }
 
-    println!("  ✅ Day {} completed!\n", day_num);
-    Ok(())
+    println!("📅 Day {}", args.day);
+    let output = ProcessCommand::new("cargo")
+        .current_dir(&args.root)
+        .args(["run", "--release", "--quiet", "--"])
+        .arg(args.day.to_string())
+        .args(["--year", &year.to_string()])
+        .args(["--format", "json", "--no-cache"])
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Failed to run the solution")?;
+
+    let answers = watch::parse_answers(&String::from_utf8_lossy(&output.stdout));
+    if answers.is_empty() {
+        println!("  ❌ No answers produced");
+    }
+    for line in watch::diff_answers(previous, &answers) {
+        println!("  {}", line);
+    }
+    Ok(answers)
 }
 
-fn run_day_string_i32(
-    title: &str,
-    solve1: fn(&str) -> String,
-    solve2: fn(&str) -> i32,
-    day_num: u32,
-) -> Result<()> {
-    println!("{}", title);
-    let input_path = format!("src/solutions/day{:02}/input.txt", day_num);
-    let input = input::read_input(&input_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read input for day {}: {}", day_num, e))?;
+/// Run each selected day's examples.txt, or write it with --extract
+fn run_examples(args: &ExamplesArgs) -> Result<()> {
+    let year = select_year(args.year)?;
+    let selection = args.days.clone().unwrap_or_else(DaySelection::all);
+    if args.extract {
+        return extract_examples(args, year.year, &selection);
+    }
 
-    let start = Instant::now();
-    let p1 = solve1(&input);
-    let d1 = start.elapsed();
-    println!("  Part 1: {} ({}µs)", p1, d1.as_micros());
+    println!("Advent of Code {} - Checking Examples", year.year);
+    let (mut passed, mut failed) = (0, 0);
+    for &day in selection.days() {
+        let Some(solution) = year.get(day) else {
+            continue;
+        };
+        let path = aoc_layout::examples_path(&args.root, year.year, day);
+        let day_examples = examples::load(&path)?;
+        if day_examples.is_empty() {
+            continue;
+        }
 
-    let start = Instant::now();
-    let p2 = solve2(&input);
-    let d2 = start.elapsed();
-    println!("  Part 2: {} ({}µs)", p2, d2.as_micros());
+        println!("\n📅 Day {}: {}", day, solution.title());
+        for example in &day_examples {
+            let record = run_part(
+                solution,
+                example.part,
+                &example.input,
+                Some(args.timeout),
+                false,
+            );
+            let summary = summarize_input(&example.input);
+            match &record.answer {
+                Some(answer) if *answer == example.answer => {
+                    passed += 1;
+                    println!("  ✅ Part {} {}: {}", example.part, summary, answer);
+                }
+                _ => {
+                    failed += 1;
+                    let got = record
+                        .answer
+                        .or(record.error.map(|e| format!("error: {}", e)))
+                        .unwrap_or_default();
+                    println!(
+                        "  ❌ Part {} {}: expected {}, got {}",
+                        example.part, summary, example.answer, got
+                    );
+                }
+            }
+        }
+    }
 
-    println!("  ✅ Day {} completed!\n", day_num);
+    println!("\n{} passed, {} failed", passed, failed);
+    if failed > 0 {
+        anyhow::bail!("{} example(s) failed", failed);
+    }
     Ok(())
 }
 
-fn run_day_u32_string(
-    title: &str,
-    solve1: fn(&str) -> u32,
-    solve2: fn(&str) -> String,
-    day_num: u32,
-) -> Result<()> {
-    println!("{}", title);
-    let input_path = format!("src/solutions/day{:02}/input.txt", day_num);
-    let input = input::read_input(&input_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read input for day {}: {}", day_num, e))?;
+/// Write examples.txt for each selected day that has a puzzle.txt
+fn extract_examples(args: &ExamplesArgs, year: u16, selection: &DaySelection) -> Result<()> {
+    for &day in selection.days() {
+        let Ok(puzzle) = std::fs::read_to_string(aoc_layout::puzzle_path(&args.root, year, day))
+        else {
+            continue;
+        };
+        let path = aoc_layout::examples_path(&args.root, year, day);
+        if path.exists() && !args.force {
+            println!("Day {}: {} exists, skipping", day, path.display());
+            continue;
+        }
+        let found = examples::extract(&puzzle);
+        if found.is_empty() {
+            println!("Day {}: no examples found", day);
+            continue;
+        }
+        std::fs::write(&path, examples::format(&found))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!(
+            "Day {}: wrote {} example(s) to {}",
+            day,
+            found.len(),
+            path.display()
+        );
+    }
+    println!("Review the extracted examples by hand; the puzzle text is only loosely structured");
+    Ok(())
+}
 
-    let start = Instant::now();
-    let p1 = solve1(&input);
-    let d1 = start.elapsed();
-    println!("  Part 1: {} ({}µs)", p1, d1.as_micros());
+/// First line of an example input, shortened for display
+fn summarize_input(input: &str) -> String {
+    let first = input.lines().next().unwrap_or("");
+    let mut summary: String = first.chars().take(24).collect();
+    if summary.len() < first.len() || input.lines().count() > 1 {
+        summary.push('…');
+    }
+    format!("[{}]", summary)
+}
 
-    let start = Instant::now();
-    let p2 = solve2(&input);
-    let d2 = start.elapsed();
-    println!("  Part 2: {} ({}µs)", p2, d2.as_micros());
+/// Read commands from stdin until `quit` or end of input
+fn run_repl(args: &ReplArgs) -> Result<()> {
+    let year = select_year(args.year)?;
+    let mut session = Session::new(year, args.root.clone());
+    println!(
+        "Advent of Code {} - type 'help' for commands, 'day N' to start",
+        year.year
+    );
+
+    let stdin = std::io::stdin();
+    let mut line = String::new();
+    loop {
+        print!("{}", session.prompt());
+        std::io::stdout().flush()?;
+        line.clear();
+        if stdin.read_line(&mut line)? == 0 {
+            println!();
+            return Ok(());
+        }
+        match session.execute(line.trim_end_matches(['\n', '\r'])) {
+            Reply::Text(text) if text.is_empty() => {}
+            Reply::Text(text) => println!("{}", text),
+            Reply::Quit => return Ok(()),
+        }
+    }
+}
 
-    println!("  ✅ Day {} completed!\n", day_num);
+/// Handle the `cache` subcommand
+fn run_cache(action: &CacheAction) -> Result<()> {
+    let path = AnswerCache::default_path();
+    match action {
+        CacheAction::Clear => {
+            if AnswerCache::clear(&path)? {
+                println!("Removed {}", path.display());
+            } else {
+                println!("No cache at {}", path.display());
+            }
+        }
+        CacheAction::Info => {
+            let answer_cache = AnswerCache::load(&path);
+            println!("{} ({} answers)", path.display(), answer_cache.len());
+        }
+    }
     Ok(())
 }
 
-fn run_day_string(
-    title: &str,
-    solve1: fn(&str) -> String,
-    solve2: fn(&str) -> String,
-    day_num: u32,
-) -> Result<()> {
-    println!("{}", title);
-    let input_path = format!("src/solutions/day{:02}/input.txt", day_num);
-    let input = input::read_input(&input_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read input for day {}: {}", day_num, e))?;
+/// Resolve the --year option, defaulting to the latest year with solutions
+fn select_year(year: Option<u16>) -> Result<&'static Year> {
+    let year = year.unwrap_or_else(solutions::latest_year);
+    solutions::year(year).with_context(|| {
+        let known: Vec<String> = solutions::YEARS
+            .iter()
+            .map(|y| y.year.to_string())
+            .collect();
+        format!(
+            "No solutions for {} (available: {})",
+            year,
+            known.join(", ")
+        )
+    })
+}
 
-    let start = Instant::now();
-    let p1 = solve1(&input);
-    let d1 = start.elapsed();
-    println!("  Part 1: {} ({}µs)", p1, d1.as_micros());
+/// Load the puzzle input: an explicit --input file wins over --input-dir,
+/// which in turn wins over the input embedded in the binary
+///
+/// A day missing from --input-dir falls back to the copy aoc-fetch keeps in
+/// the user's cache directory. Any of these files may be gzip-compressed.
+fn load_input(
+    solution: &dyn Solution,
+    year: u16,
+    override_path: Option<&PathBuf>,
+    input_dir: Option<&PathBuf>,
+) -> Result<String> {
+    match (override_path, input_dir) {
+        (Some(path), _) => Ok(input::read_input(&path.to_string_lossy())?),
+        (None, Some(dir)) => {
+            let day = solution.day();
+            input::read_day_input(&[dir.as_path()], year, day)
+                .with_context(|| format!("Failed to read input for day {}", day))
+        }
+        (None, None) => Ok(solution.input().to_string()),
+    }
+}
+
+/// Run one part of a solution and record its result
+fn run_part(
+    solution: &'static dyn Solution,
+    part: u32,
+    input: &str,
+    timeout: Option<Duration>,
+    measure_memory: bool,
+) -> PartRecord {
+    // Memory is counted per thread, so measure on whichever thread solves
+    let solve = move |input: &str| {
+        if measure_memory {
+            let (result, stats) = memory::measure(|| solution.solve(part, input));
+            (result, Some(stats))
+        } else {
+            (solution.solve(part, input), None)
+        }
+    };
 
     let start = Instant::now();
-    let p2 = solve2(&input);
-    let d2 = start.elapsed();
-    println!("  Part 2: {} ({}µs)", p2, d2.as_micros());
+    let (result, stats) = match timeout {
+        Some(limit) => {
+            let input = input.to_string();
+            match watchdog::run_with_timeout(limit, move || solve(&input)) {
+                Ok((result, stats)) => (result.map_err(|e| e.to_string()), stats),
+                Err(e) => (Err(e.to_string()), None),
+            }
+        }
+        None => {
+            let (result, stats) = solve(input);
+            (result.map_err(|e| e.to_string()), stats)
+        }
+    };
+    let elapsed = start.elapsed();
+    PartRecord::new(
+        solution.day(),
+        part,
+        solution.title(),
+        result.map(|a| a.to_string()),
+        elapsed,
+    )
+    .with_memory(stats)
+}
+
+/// Benchmark the selected days and compare against a baseline if one was given
+fn run_bench(args: &BenchArgs) -> Result<()> {
+    let year = select_year(args.year)?;
+    let selection = args.days.clone().unwrap_or_else(DaySelection::all);
+    let previous = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    let mut current = Baseline::default();
+    let mut regressions = 0;
+
+    println!(
+        "🎄 Advent of Code {} - Benchmark ({} runs, {} warm-up) 🎄\n",
+        year.year, args.iterations, args.warmup
+    );
+
+    for &day in selection.days() {
+        let Some(solution) = year.get(day) else {
+            println!("❌ Day {} not implemented yet", day);
+            continue;
+        };
+        let input = load_input(solution, year.year, None, args.input_dir.as_ref())?;
+
+        println!("📅 Day {}: {}", day, solution.title());
+        for part in [1, 2] {
+            if args.part.is_some_and(|p| p != part) {
+                continue;
+            }
+
+            let result = bench::bench_part(
+                solution,
+                part,
+                &input,
+                args.warmup as usize,
+                args.iterations as usize,
+            )
+            .map_err(|e| anyhow::anyhow!("Day {}, part {}: {}", day, part, e))?;
+
+            println!("  Part {}: {}", part, describe(&result.total));
+            println!("    parse: {}", describe(&result.parse));
+            println!("    solve: {}", describe(&result.solve));
+
+            if let Some(change) = previous.as_ref().and_then(|b| b.change(&result)) {
+                let percent = change * 100.0;
+                if percent > args.threshold {
+                    regressions += 1;
+                    println!("    ⚠️  regression: {:+.1}% against baseline", percent);
+                } else {
+                    println!("    {:+.1}% against baseline", percent);
+                }
+            }
+
+            current.record(&result);
+        }
+        println!();
+    }
+
+    if let Some(path) = &args.save_baseline {
+        current.save(path)?;
+        println!("💾 Baseline saved to {}", path.display());
+    }
 
-    println!("  ✅ Day {} completed!\n", day_num);
+    if regressions > 0 {
+        anyhow::bail!(
+            "{} part(s) regressed by more than {}%",
+            regressions,
+            args.threshold
+        );
+    }
     Ok(())
 }
+
+fn describe(stats: &Stats) -> String {
+    format!(
+        "median {} (min {}, mean {}, stddev {})",
+        format_micros(stats.median),
+        format_micros(stats.min),
+        format_micros(stats.mean),
+        format_micros(stats.stddev)
+    )
+}
@@ -0,0 +1,225 @@
+// Benchmarking support: repeated timing, summary statistics and baselines
+
+use crate::solutions::Solution;
+use crate::utils::input::ParseError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Summary statistics over a set of timing samples, in microseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Compute statistics for the given samples (sample standard deviation)
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        };
+        let mean = sorted.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        })
+    }
+}
+
+/// Timing results for one part of one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartBench {
+    pub day: u32,
+    pub part: u32,
+    /// Time spent in the parsing step alone
+    pub parse: Stats,
+    /// Time spent solving, excluding parsing
+    pub solve: Stats,
+    /// Time for the whole part, parsing included
+    pub total: Stats,
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
+
+/// Benchmark one part: `warmup` untimed runs followed by `iterations` timed runs
+///
+/// Each timed iteration measures the parsing step on its own and then the full
+/// part; the solve time for that iteration is the difference between the two.
+pub fn bench_part(
+    solution: &dyn Solution,
+    part: u32,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<PartBench, ParseError> {
+    for _ in 0..warmup {
+        std::hint::black_box(solution.solve(part, input)?);
+    }
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solve_samples = Vec::with_capacity(iterations);
+    let mut total_samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        solution.parse(input)?;
+        let parse = micros(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(solution.solve(part, input)?);
+        let total = micros(start.elapsed());
+
+        parse_samples.push(parse);
+        solve_samples.push((total - parse).max(0.0));
+        total_samples.push(total);
+    }
+
+    // The sample vectors are never empty, so statistics always exist
+    let stats = |samples: &[f64]| Stats::from_samples(samples).expect("at least one sample");
+    Ok(PartBench {
+        day: solution.day(),
+        part,
+        parse: stats(&parse_samples),
+        solve: stats(&solve_samples),
+        total: stats(&total_samples),
+    })
+}
+
+/// Median total time per day and part from an earlier run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    entries: BTreeMap<String, f64>,
+}
+
+impl Baseline {
+    fn key(day: u32, part: u32) -> String {
+        format!("day{:02}.part{}", day, part)
+    }
+
+    pub fn record(&mut self, bench: &PartBench) {
+        self.entries
+            .insert(Self::key(bench.day, bench.part), bench.total.median);
+    }
+
+    pub fn median(&self, day: u32, part: u32) -> Option<f64> {
+        self.entries.get(&Self::key(day, part)).copied()
+    }
+
+    /// Relative change of the current median against the baseline, e.g. 0.25 for 25% slower
+    pub fn change(&self, bench: &PartBench) -> Option<f64> {
+        let baseline = self.median(bench.day, bench.part)?;
+        if baseline <= 0.0 {
+            return None;
+        }
+        Some(bench.total.median / baseline - 1.0)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read baseline {}: {}", path.display(), e))?;
+        serde_json::from_str(&text)
+            .map_err(|e| anyhow::anyhow!("Invalid baseline {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let text = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, text + "\n")
+            .map_err(|e| anyhow::anyhow!("Failed to write baseline {}: {}", path.display(), e))
+    }
+}
+
+/// Format a duration given in microseconds with a readable unit
+pub fn format_micros(us: f64) -> String {
+    if us >= 1_000_000.0 {
+        format!("{:.2}s", us / 1_000_000.0)
+    } else if us >= 1_000.0 {
+        format!("{:.2}ms", us / 1_000.0)
+    } else {
+        format!("{:.1}µs", us)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[4.0, 1.0, 3.0, 2.0]).unwrap();
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.2910).abs() < 1e-3);
+
+        let single = Stats::from_samples(&[7.0]).unwrap();
+        assert_eq!((single.median, single.stddev), (7.0, 0.0));
+
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_baseline_change() {
+        let stats = |median| Stats {
+            min: median,
+            median,
+            mean: median,
+            stddev: 0.0,
+        };
+        let bench = |median| PartBench {
+            day: 5,
+            part: 2,
+            parse: stats(0.0),
+            solve: stats(median),
+            total: stats(median),
+        };
+
+        let mut baseline = Baseline::default();
+        baseline.record(&bench(100.0));
+        assert_eq!(baseline.median(5, 2), Some(100.0));
+        assert_eq!(baseline.change(&bench(125.0)), Some(0.25));
+        assert_eq!(baseline.median(5, 1), None);
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(json, r#"{"day05.part2":100.0}"#);
+    }
+
+    #[test]
+    fn test_bench_part() {
+        let solution = crate::solutions::get(2017, 1).unwrap();
+        let bench = bench_part(solution, 1, "1122", 1, 3).unwrap();
+        assert_eq!((bench.day, bench.part), (1, 1));
+        assert!(bench.total.min <= bench.total.median);
+    }
+
+    #[test]
+    fn test_format_micros() {
+        assert_eq!(format_micros(12.34), "12.3µs");
+        assert_eq!(format_micros(1_500.0), "1.50ms");
+        assert_eq!(format_micros(2_000_000.0), "2.00s");
+    }
+}
@@ -0,0 +1,185 @@
+// On-disk cache of answers, so unchanged days are not recomputed on every run
+
+use crate::solutions::Solution;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// 64-bit FNV-1a hash of the input, as 16 hex digits
+///
+/// Unlike `DefaultHasher` this is stable across Rust releases, so cache
+/// entries survive toolchain upgrades.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Fingerprint of the sources this binary was built from
+///
+/// Part of every [`CacheKey`], so editing a solver invalidates its cached
+/// answers even if nobody remembers to bump its `version()`.
+pub const SOURCE_HASH: &str = env!("AOC_SOURCE_HASH");
+
+/// Everything an answer depends on: the puzzle, the input and the solver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheKey {
+    pub year: u16,
+    pub day: u32,
+    pub part: u32,
+    pub input_hash: String,
+    pub version: u32,
+    pub source_hash: String,
+}
+
+impl CacheKey {
+    pub fn new(year: u16, solution: &dyn Solution, part: u32, input_hash: &str) -> Self {
+        Self {
+            year,
+            day: solution.day(),
+            part,
+            input_hash: input_hash.to_string(),
+            version: solution.version(),
+            source_hash: SOURCE_HASH.to_string(),
+        }
+    }
+
+    fn encode(&self) -> String {
+        format!(
+            "{}/{:02}/{}/{}/v{}/{}",
+            self.year, self.day, self.part, self.input_hash, self.version, self.source_hash
+        )
+    }
+}
+
+/// A previously computed answer and how long it originally took
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedAnswer {
+    pub answer: String,
+    pub time_us: u64,
+}
+
+/// Answers stored in a JSON file under the user's cache directory
+#[derive(Debug, Default)]
+pub struct AnswerCache {
+    entries: BTreeMap<String, CachedAnswer>,
+    dirty: bool,
+}
+
+impl AnswerCache {
+    /// `answers.json` in the shared cache directory, see [`aoc_layout::cache_dir`]
+    pub fn default_path() -> PathBuf {
+        aoc_layout::cache_dir().join("answers.json")
+    }
+
+    /// Load the cache; a missing or unreadable file gives an empty cache
+    pub fn load(path: &Path) -> Self {
+        let entries = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        Self {
+            entries,
+            dirty: false,
+        }
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<&CachedAnswer> {
+        self.entries.get(&key.encode())
+    }
+
+    pub fn insert(&mut self, key: &CacheKey, answer: CachedAnswer) {
+        if self.entries.get(&key.encode()) != Some(&answer) {
+            self.entries.insert(key.encode(), answer);
+            self.dirty = true;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Write the cache back if anything was added
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(&self.entries)?;
+        std::fs::write(path, text + "\n")
+            .map_err(|e| anyhow::anyhow!("Failed to write cache {}: {}", path.display(), e))
+    }
+
+    /// Delete the cache file, returning whether there was one
+    pub fn clear(path: &Path) -> anyhow::Result<bool> {
+        match std::fs::remove_file(path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(anyhow::anyhow!(
+                "Failed to remove cache {}: {}",
+                path.display(),
+                e
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1122"), input_hash("1122\n"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let solution = crate::solutions::get(2017, 1).unwrap();
+        let key = CacheKey::new(2017, solution, 2, &input_hash("1212"));
+        assert_eq!(
+            key.encode(),
+            format!("2017/01/2/1fb2a7f1031a9b69/v1/{}", SOURCE_HASH)
+        );
+
+        let path = std::env::temp_dir()
+            .join(format!("aoc-cache-test-{}", std::process::id()))
+            .join("answers.json");
+        let mut cache = AnswerCache::load(&path);
+        assert!(cache.is_empty());
+
+        let answer = CachedAnswer {
+            answer: "6".to_string(),
+            time_us: 12,
+        };
+        cache.insert(&key, answer.clone());
+        cache.save(&path).unwrap();
+
+        let reloaded = AnswerCache::load(&path);
+        assert_eq!(reloaded.get(&key), Some(&answer));
+        let other_version = CacheKey {
+            version: 2,
+            ..key.clone()
+        };
+        assert_eq!(reloaded.get(&other_version), None);
+        let other_build = CacheKey {
+            source_hash: "0000000000000000".to_string(),
+            ..key
+        };
+        assert_eq!(reloaded.get(&other_build), None);
+
+        assert!(AnswerCache::clear(&path).unwrap());
+        assert!(!AnswerCache::clear(&path).unwrap());
+        std::fs::remove_dir(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,354 @@
+// Puzzle examples: pulled out of puzzle.txt into examples.txt and run against the solvers
+
+use anyhow::{bail, Context, Result};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// An example input and the answer the puzzle text gives for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+}
+
+impl Example {
+    fn new(part: u32, input_lines: &[&str], answer: &str) -> Self {
+        Self {
+            part,
+            input: join_input(input_lines),
+            answer: answer.to_string(),
+        }
+    }
+}
+
+/// Input lines as they would appear in an input file, with a trailing newline
+fn join_input(lines: &[&str]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Phrases that introduce the answer for a block example
+const ANSWER_MARKERS: &[&str] = &[
+    "in this example",
+    "in the example above",
+    "therefore",
+    "in total",
+];
+
+/// Phrases in part two that refer back to part one's example
+const REUSE_MARKERS: &[&str] = &["example above", "above example", "previous example"];
+
+/// Separators between an inline example and its answer, e.g. `1122 produces 3`
+const INLINE_SEPARATORS: &[&str] = &[" produces ", " becomes ", " is ", ", "];
+
+/// Best-effort extraction of examples from the plain text of a puzzle
+///
+/// Two shapes are recognised: lists of one-line examples followed by their
+/// answers (`1122 produces 3`), and blocks introduced by a line such as
+/// `For example, given the following spreadsheet:` whose answer is stated
+/// later (`In this example, ... = 18.`). The result is a starting point for
+/// `examples.txt` and should be reviewed by hand.
+pub fn extract(puzzle: &str) -> Vec<Example> {
+    let lines: Vec<&str> = puzzle
+        .lines()
+        .take_while(|line| !line.starts_with("Both parts of this puzzle"))
+        .filter(|line| !line.starts_with("Your puzzle answer was"))
+        .collect();
+    let split = lines
+        .iter()
+        .position(|line| line.trim() == "--- Part Two ---")
+        .unwrap_or(lines.len());
+
+    let mut examples = Vec::new();
+    let part1 = &lines[..split];
+    let block1 = extract_block(part1, 1, &mut examples);
+    extract_inline(part1, 1, &mut examples);
+
+    if split < lines.len() {
+        let part2 = &lines[split + 1..];
+        if extract_block(part2, 2, &mut examples).is_none() {
+            if let Some(block) = block1 {
+                extract_reused(part2, &block, &mut examples);
+            }
+        }
+        extract_inline(part2, 2, &mut examples);
+    }
+    examples
+}
+
+/// The first example block of a part and its stated answer; returns the block
+fn extract_block<'a>(
+    lines: &[&'a str],
+    part: u32,
+    examples: &mut Vec<Example>,
+) -> Option<Vec<&'a str>> {
+    let start = lines.windows(3).position(|window| {
+        let intro = window[0].to_lowercase();
+        intro.ends_with(':')
+            && (intro.contains("example") || intro.contains("following"))
+            && window[1].trim().is_empty()
+            && !window[2].trim().is_empty()
+    })? + 2;
+    let len = lines[start..]
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .unwrap_or(lines.len() - start);
+    let block = lines[start..start + len].to_vec();
+    if block.iter().any(|line| is_prose(line)) {
+        return None;
+    }
+
+    let answer = lines[start + len..]
+        .iter()
+        .take_while(|line| !line.trim_end().ends_with('?'))
+        .filter(|line| !line.trim_end().ends_with(':'))
+        .find_map(|line| {
+            let lower = line.to_lowercase();
+            let at = ANSWER_MARKERS
+                .iter()
+                .filter_map(|marker| lower.find(marker).map(|at| at + marker.len()))
+                .min()?;
+            stated_answer(&line[at..])
+        });
+    if let Some(answer) = answer {
+        examples.push(Example::new(part, &block, &answer));
+    }
+    Some(block)
+}
+
+/// A sentence rather than puzzle input
+fn is_prose(line: &str) -> bool {
+    let line = line.trim_end();
+    (line.ends_with('.') || line.ends_with(':')) && line.contains(' ')
+}
+
+/// Part two's answer for part one's example, e.g. `In the example above, there were 2 groups`
+fn extract_reused(lines: &[&str], block: &[&str], examples: &mut Vec<Example>) {
+    let answer = lines.iter().find_map(|line| {
+        let lower = line.to_lowercase();
+        let at = REUSE_MARKERS
+            .iter()
+            .filter_map(|marker| lower.find(marker).map(|at| at + marker.len()))
+            .min()?;
+        stated_answer(&line[at..])
+    });
+    if let Some(answer) = answer {
+        examples.push(Example::new(2, block, &answer));
+    }
+}
+
+/// The last run of at least two one-line examples in a part
+fn extract_inline(lines: &[&str], part: u32, examples: &mut Vec<Example>) {
+    let mut runs: Vec<Vec<(String, String)>> = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        match inline_example(line) {
+            Some(example) => current.push(example),
+            None => runs.push(std::mem::take(&mut current)),
+        }
+    }
+    runs.push(current);
+
+    if let Some(run) = runs.into_iter().rfind(|run| run.len() >= 2) {
+        for (input, answer) in run {
+            let input_lines: Vec<&str> = if input.is_empty() {
+                Vec::new()
+            } else {
+                vec![&input]
+            };
+            examples.push(Example::new(part, &input_lines, &answer));
+        }
+    }
+}
+
+/// Split `INPUT produces ANSWER ...` into its input and answer
+fn inline_example(line: &str) -> Option<(String, String)> {
+    let (at, separator) = INLINE_SEPARATORS
+        .iter()
+        .filter_map(|separator| line.find(separator).map(|at| (at, separator)))
+        .min()?;
+    let input = match &line[..at] {
+        "The empty string" => "",
+        input
+            if !input.contains(char::is_whitespace)
+                && !input.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            input
+        }
+        _ => return None,
+    };
+    let answer = stated_answer(&line[at + separator.len()..])?;
+    Some((input.to_string(), answer))
+}
+
+/// The answer stated in a sentence: whatever follows `answer`, or the result
+/// of a sum (`1 + 2 = 3`), otherwise the first word containing a digit
+fn stated_answer(text: &str) -> Option<String> {
+    let text = match text.find("answer") {
+        Some(at) => &text[at..],
+        None => text,
+    };
+    let text = match text.rfind(" = ") {
+        Some(at) => &text[at + 3..],
+        None => text,
+    };
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != '-'))
+        .find(|word| word.chars().any(|c| c.is_ascii_digit()))
+        .map(str::to_string)
+}
+
+/// Render examples in the `examples.txt` format
+///
+/// Each example is a `--- Part N: ANSWER ---` header followed by its input.
+pub fn format(examples: &[Example]) -> String {
+    let mut text = String::new();
+    for (i, example) in examples.iter().enumerate() {
+        if i > 0 {
+            text.push('\n');
+        }
+        let _ = writeln!(text, "--- Part {}: {} ---", example.part, example.answer);
+        text.push_str(&example.input);
+    }
+    text
+}
+
+/// Parse the `examples.txt` format written by [`format`]
+pub fn parse(text: &str) -> Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    let mut current: Option<(u32, &str, Vec<&str>)> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        if let Some((part, answer)) = header(line) {
+            examples.extend(current.take().map(finish));
+            current = Some((part, answer, Vec::new()));
+        } else if let Some((_, _, input)) = current.as_mut() {
+            input.push(line);
+        } else if !line.trim().is_empty() {
+            bail!(
+                "line {}: expected a '--- Part N: ANSWER ---' header, found '{}'",
+                i + 1,
+                line
+            );
+        }
+    }
+    examples.extend(current.map(finish));
+    Ok(examples)
+}
+
+/// `--- Part 1: 18 ---` as part and answer
+fn header(line: &str) -> Option<(u32, &str)> {
+    let inner = line.strip_prefix("--- Part ")?.strip_suffix(" ---")?;
+    let (part, answer) = inner.split_once(": ")?;
+    let part = part.parse().ok().filter(|part| matches!(part, 1 | 2))?;
+    Some((part, answer))
+}
+
+fn finish((part, answer, mut input): (u32, &str, Vec<&str>)) -> Example {
+    while input.last().is_some_and(|line| line.trim().is_empty()) {
+        input.pop();
+    }
+    Example::new(part, &input, answer)
+}
+
+/// Load a day's examples; a missing file means the day has none
+pub fn load(path: &Path) -> Result<Vec<Example>> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => parse(&text).with_context(|| format!("Invalid {}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(anyhow::anyhow!("Failed to read {}: {}", path.display(), e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions;
+
+    #[test]
+    fn test_extract_inline() {
+        let puzzle = "\
+https://adventofcode.com/2017/day/1
+
+--- Day 1: Inverse Captcha ---
+For example:
+
+1122 produces a sum of 3 (1 + 2) because the first digit (1) matches the second digit.
+1111 produces 4 because each digit (all 1) matches the next.
+What is the solution to your captcha?
+
+Your puzzle answer was 1251.
+
+--- Part Two ---
+The empty string becomes a2582a3a0e66e6e86e3812dcb672a272.
+1,2,3 becomes 3efbe78a8d82f29979031a4aa0b16a9d.
+";
+        let examples = extract(puzzle);
+        assert_eq!(
+            examples,
+            [
+                Example::new(1, &["1122"], "3"),
+                Example::new(1, &["1111"], "4"),
+                Example::new(2, &[], "a2582a3a0e66e6e86e3812dcb672a272"),
+                Example::new(2, &["1,2,3"], "3efbe78a8d82f29979031a4aa0b16a9d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_block() {
+        let puzzle = "\
+For example, given the following spreadsheet:
+
+5 1 9 5
+7 5 3
+
+The first row's difference is 8.
+In this example, the spreadsheet's checksum would be 8 + 4 = 12.
+
+What is the checksum?
+
+--- Part Two ---
+In the example above, there were 2 groups: one with 0 and another.
+";
+        let examples = extract(puzzle);
+        assert_eq!(
+            examples,
+            [
+                Example::new(1, &["5 1 9 5", "7 5 3"], "12"),
+                Example::new(2, &["5 1 9 5", "7 5 3"], "2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        let examples = vec![
+            Example::new(1, &["0 <-> 2", "", "  indented"], "6"),
+            Example::new(2, &[], "a258"),
+        ];
+        let text = format(&examples);
+        assert!(text.starts_with("--- Part 1: 6 ---\n0 <-> 2\n\n  indented\n\n"));
+        assert_eq!(parse(&text).unwrap(), examples);
+        assert!(parse("1122\n--- Part 1: 3 ---\n").is_err());
+    }
+
+    #[test]
+    fn test_examples_pass() {
+        let year = solutions::year(2017).unwrap();
+        for solution in year.solutions {
+            let path = aoc_layout::examples_path(Path::new(""), year.year, solution.day());
+            for example in load(&path).unwrap() {
+                let answer = solution.solve(example.part, &example.input);
+                assert_eq!(
+                    answer.map(|a| a.to_string()).ok().as_deref(),
+                    Some(example.answer.as_str()),
+                    "day {} part {} example:\n{}",
+                    solution.day(),
+                    example.part,
+                    example.input
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// Counting allocator used to report memory usage per part
+
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Global allocator that forwards to the system allocator and, once enabled,
+/// counts allocations made on each thread
+///
+/// Install it in a binary with `#[global_allocator]`; counting stays off
+/// (a single atomic load per call) until [`enable`] is called.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counters {
+    current: isize,
+    peak: isize,
+    total: usize,
+    allocations: usize,
+}
+
+const ZERO: Counters = Counters {
+    current: 0,
+    peak: 0,
+    total: 0,
+    allocations: 0,
+};
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(ZERO) };
+}
+
+fn record_alloc(size: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // Fails only while the thread is being torn down, when nothing is measured
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.current += size as isize;
+        c.peak = c.peak.max(c.current);
+        c.total += size;
+        c.allocations += 1;
+        counters.set(c);
+    });
+}
+
+fn record_dealloc(size: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.current -= size as isize;
+        counters.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Turn on allocation counting for all threads
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Memory used while running one piece of work
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemoryStats {
+    /// Highest number of live bytes above the level at the start
+    pub peak_bytes: u64,
+    /// Sum of all allocation sizes, reallocations included
+    pub total_bytes: u64,
+    pub allocations: u64,
+}
+
+/// Run `work` on the current thread and report the allocations it made
+///
+/// Only allocations on the calling thread are counted, so concurrent work on
+/// other threads does not skew the numbers. Returns zeros unless counting has
+/// been enabled and [`CountingAllocator`] is the global allocator.
+pub fn measure<R>(work: impl FnOnce() -> R) -> (R, MemoryStats) {
+    COUNTERS.with(|counters| counters.set(ZERO));
+    let result = work();
+    let c = COUNTERS.with(Cell::get);
+    let stats = MemoryStats {
+        peak_bytes: c.peak.max(0) as u64,
+        total_bytes: c.total as u64,
+        allocations: c.allocations as u64,
+    };
+    (result, stats)
+}
+
+/// Format a byte count with a binary unit
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        enable();
+        let (sum, stats) = measure(|| {
+            let first: Vec<u64> = vec![1; 1000];
+            let second: Vec<u64> = vec![2; 500];
+            drop(first);
+            let third: Vec<u64> = vec![3; 100];
+            second.iter().chain(&third).sum::<u64>()
+        });
+        assert_eq!(sum, 1300);
+        assert_eq!(stats.allocations, 3);
+        assert_eq!(stats.total_bytes, 8 * 1600);
+        assert_eq!(stats.peak_bytes, 8 * 1500);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,117 @@
+// Runner support - shared logic behind the solution runner binary
+
+use std::fmt;
+use std::str::FromStr;
+
+pub mod bench;
+pub mod cache;
+pub mod examples;
+pub mod memory;
+pub mod parallel;
+pub mod repl;
+pub mod report;
+pub mod verify;
+pub mod watch;
+pub mod watchdog;
+
+/// First and last puzzle days
+pub const FIRST_DAY: u32 = 1;
+pub const LAST_DAY: u32 = 25;
+
+/// A set of days selected on the command line, e.g. `3-7,12`
+/// Days are kept sorted and de-duplicated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection {
+    days: Vec<u32>,
+}
+
+impl DaySelection {
+    /// Select every day
+    pub fn all() -> Self {
+        Self {
+            days: (FIRST_DAY..=LAST_DAY).collect(),
+        }
+    }
+
+    pub fn days(&self) -> &[u32] {
+        &self.days
+    }
+}
+
+/// Error raised for an invalid day selection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelectionError(String);
+
+impl fmt::Display for DaySelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for DaySelectionError {}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |token: &str| -> Result<u32, DaySelectionError> {
+            let day: u32 = token
+                .trim()
+                .parse()
+                .map_err(|_| DaySelectionError(format!("invalid day '{}'", token.trim())))?;
+            if !(FIRST_DAY..=LAST_DAY).contains(&day) {
+                return Err(DaySelectionError(format!(
+                    "day {} is outside {}-{}",
+                    day, FIRST_DAY, LAST_DAY
+                )));
+            }
+            Ok(day)
+        };
+
+        let mut days = Vec::new();
+        for item in s.split(',') {
+            match item.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start)?, parse_day(end)?);
+                    if start > end {
+                        return Err(DaySelectionError(format!(
+                            "invalid range '{}'",
+                            item.trim()
+                        )));
+                    }
+                    days.extend(start..=end);
+                }
+                None => days.push(parse_day(item)?),
+            }
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(Self { days })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_selection() {
+        let selection: DaySelection = "3-7,12".parse().unwrap();
+        assert_eq!(selection.days(), &[3, 4, 5, 6, 7, 12]);
+
+        let selection: DaySelection = "12, 3,3-4".parse().unwrap();
+        assert_eq!(selection.days(), &[3, 4, 12]);
+
+        assert_eq!(DaySelection::all().days().len(), 25);
+    }
+
+    #[test]
+    fn test_day_selection_errors() {
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("7-3".parse::<DaySelection>().is_err());
+        assert!("x".parse::<DaySelection>().is_err());
+        assert!("3,".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,93 @@
+// Small thread pool that runs independent tasks but yields results in order
+
+use std::collections::BTreeMap;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+/// Number of worker threads for a `--jobs` value, where 0 means one per CPU
+pub fn resolve_jobs(jobs: usize) -> usize {
+    if jobs == 0 {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        jobs
+    }
+}
+
+/// Run `work` over every task on up to `jobs` threads
+///
+/// Results are handed to `emit` in task order as soon as all earlier tasks
+/// have finished, so output stays deterministic however the work is scheduled.
+pub fn run_ordered<T, R, F, G>(jobs: usize, tasks: Vec<T>, work: F, mut emit: G)
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+    G: FnMut(usize, R),
+{
+    let jobs = jobs.clamp(1, tasks.len().max(1));
+    if jobs == 1 {
+        for (index, task) in tasks.into_iter().enumerate() {
+            emit(index, work(task));
+        }
+        return;
+    }
+
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let (queue, work) = (&queue, &work);
+            scope.spawn(move || loop {
+                let next = queue.lock().unwrap().next();
+                let Some((index, task)) = next else { break };
+                if sender.send((index, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next) {
+                emit(next, result);
+                next += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_ordered() {
+        for jobs in [1, 4] {
+            let mut seen = Vec::new();
+            run_ordered(
+                jobs,
+                (0..20u64).collect(),
+                |n| {
+                    // Earlier tasks finish last, so results arrive out of order
+                    thread::sleep(Duration::from_millis(20 - n));
+                    n * n
+                },
+                |index, square| seen.push((index, square)),
+            );
+            let expected: Vec<_> = (0..20u64).map(|n| (n as usize, n * n)).collect();
+            assert_eq!(seen, expected);
+        }
+    }
+
+    #[test]
+    fn test_resolve_jobs() {
+        assert_eq!(resolve_jobs(3), 3);
+        assert!(resolve_jobs(0) >= 1);
+    }
+}
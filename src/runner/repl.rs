@@ -0,0 +1,362 @@
+// Interactive session on top of the solution registry: load a day, edit its input, run parts
+
+use crate::runner::bench::format_micros;
+use crate::runner::{examples, watchdog};
+use crate::solutions::{Solution, Year};
+use crate::utils::input;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// How long a part may run before the session gives up on it
+const SOLVE_LIMIT: Duration = Duration::from_secs(60);
+
+pub const HELP: &str = "\
+Commands:
+  day N            load day N with its embedded input
+  input            show the current input with line numbers
+  load PATH        replace the input with a file (gzip allowed, - for stdin)
+  example [N]      list the day's examples, or load example N
+  paste            replace the input with the lines that follow, ending with a lone '.'
+  add LINE         append a line
+  set N LINE       replace line N
+  del N            delete line N
+  clear            empty the input
+  1, 2             run part 1 or 2 on the current input
+  inspect          show the structures the solver builds from the input
+  help             show this list
+  quit             leave the session";
+
+/// What the caller should do after a command
+#[derive(Debug, PartialEq, Eq)]
+pub enum Reply {
+    Text(String),
+    Quit,
+}
+
+/// A REPL session: the loaded day and its editable input
+pub struct Session {
+    year: &'static Year,
+    root: PathBuf,
+    solution: Option<&'static dyn Solution>,
+    lines: Vec<String>,
+    /// Lines collected by `paste`, until the terminating `.`
+    pasting: Option<Vec<String>>,
+}
+
+impl Session {
+    /// `root` is the repository root that `example` reads examples.txt from
+    pub fn new(year: &'static Year, root: PathBuf) -> Self {
+        Self {
+            year,
+            root,
+            solution: None,
+            lines: Vec::new(),
+            pasting: None,
+        }
+    }
+
+    /// Prompt showing the loaded day, or `>` while pasting
+    pub fn prompt(&self) -> String {
+        match (&self.pasting, self.solution) {
+            (Some(_), _) => "> ".to_string(),
+            (None, Some(solution)) => format!("day{:02}> ", solution.day()),
+            (None, None) => format!("{}> ", self.year.year),
+        }
+    }
+
+    /// The input as a solver sees it
+    pub fn input(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| format!("{}\n", line))
+            .collect()
+    }
+
+    /// Handle one line typed by the user
+    pub fn execute(&mut self, line: &str) -> Reply {
+        if let Some(pasted) = self.pasting.as_mut() {
+            if line.trim_end() != "." {
+                pasted.push(line.to_string());
+                return Reply::Text(String::new());
+            }
+            self.lines = self.pasting.take().unwrap_or_default();
+            return Reply::Text(format!("{} lines", self.lines.len()));
+        }
+
+        let line = line.trim_end();
+        let (command, rest) = line
+            .trim_start()
+            .split_once(' ')
+            .unwrap_or((line.trim(), ""));
+        let text = match command {
+            "" => String::new(),
+            "help" | "?" => HELP.to_string(),
+            "quit" | "exit" => return Reply::Quit,
+            "day" => self.load_day(rest),
+            _ if self.solution.is_none() => "No day loaded; try 'day 7'".to_string(),
+            "input" => self.show_input(),
+            "load" => self.load_file(rest),
+            "example" => self.load_example(rest),
+            "paste" => {
+                self.pasting = Some(Vec::new());
+                "Paste the input, then a line with a single '.'".to_string()
+            }
+            "add" => {
+                self.lines.push(rest.to_string());
+                format!("{} lines", self.lines.len())
+            }
+            "set" => self.set_line(rest),
+            "del" => self.delete_line(rest),
+            "clear" => {
+                self.lines.clear();
+                "Input cleared".to_string()
+            }
+            "1" | "2" | "part1" | "part2" => self.run(if command.ends_with('1') { 1 } else { 2 }),
+            "inspect" => self.inspect(),
+            _ => format!("Unknown command '{}'; try 'help'", command),
+        };
+        Reply::Text(text)
+    }
+
+    fn load_day(&mut self, arg: &str) -> String {
+        let Ok(day) = arg.trim().parse::<u32>() else {
+            return "Usage: day N".to_string();
+        };
+        let Some(solution) = self.year.get(day) else {
+            return format!("No solution for {} day {}", self.year.year, day);
+        };
+        self.solution = Some(solution);
+        self.lines = solution.input().lines().map(str::to_string).collect();
+        format!(
+            "Day {}: {} ({} input lines)",
+            day,
+            solution.title(),
+            self.lines.len()
+        )
+    }
+
+    fn show_input(&self) -> String {
+        if self.lines.is_empty() {
+            return "(empty)".to_string();
+        }
+        let width = self.lines.len().to_string().len();
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| format!("{:>width$}  {}", i + 1, line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Replace the input with a file, read like `--input`: gzip is
+    /// decompressed and `-` reads stdin
+    fn load_file(&mut self, path: &str) -> String {
+        match input::read_input(path.trim()) {
+            Ok(text) => {
+                self.lines = text.lines().map(str::to_string).collect();
+                format!("{} lines", self.lines.len())
+            }
+            Err(e) => format!("Failed to load input: {}", e),
+        }
+    }
+
+    fn load_example(&mut self, arg: &str) -> String {
+        let Some(solution) = self.solution else {
+            return String::new();
+        };
+        let path = aoc_layout::examples_path(&self.root, self.year.year, solution.day());
+        let day_examples = match examples::load(&path) {
+            Ok(day_examples) if day_examples.is_empty() => {
+                return format!("No examples in {}", path.display())
+            }
+            Ok(day_examples) => day_examples,
+            Err(e) => return e.to_string(),
+        };
+
+        if arg.trim().is_empty() {
+            return day_examples
+                .iter()
+                .enumerate()
+                .map(|(i, example)| {
+                    let first = example.input.lines().next().unwrap_or("");
+                    format!(
+                        "{}. part {} → {}  {}",
+                        i + 1,
+                        example.part,
+                        example.answer,
+                        first
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+        match arg.trim().parse::<usize>() {
+            Ok(n) if (1..=day_examples.len()).contains(&n) => {
+                let example = &day_examples[n - 1];
+                self.lines = example.input.lines().map(str::to_string).collect();
+                format!(
+                    "Example {}: part {} should give {} ({} lines)",
+                    n,
+                    example.part,
+                    example.answer,
+                    self.lines.len()
+                )
+            }
+            _ => format!("Usage: example [1-{}]", day_examples.len()),
+        }
+    }
+
+    /// Parse a 1-based line number that must refer to an existing line
+    fn line_index(&self, arg: &str) -> Option<usize> {
+        arg.parse::<usize>()
+            .ok()
+            .filter(|n| (1..=self.lines.len()).contains(n))
+            .map(|n| n - 1)
+    }
+
+    fn set_line(&mut self, args: &str) -> String {
+        let (number, text) = args.split_once(' ').unwrap_or((args, ""));
+        match self.line_index(number) {
+            Some(i) => {
+                self.lines[i] = text.to_string();
+                format!("{}  {}", i + 1, text)
+            }
+            None => format!("Usage: set N LINE, with N from 1 to {}", self.lines.len()),
+        }
+    }
+
+    fn delete_line(&mut self, arg: &str) -> String {
+        match self.line_index(arg.trim()) {
+            Some(i) => {
+                let removed = self.lines.remove(i);
+                format!("Deleted {}  {}", i + 1, removed)
+            }
+            None => format!("Usage: del N, with N from 1 to {}", self.lines.len()),
+        }
+    }
+
+    fn run(&self, part: u32) -> String {
+        let Some(solution) = self.solution else {
+            return String::new();
+        };
+        let input = self.input();
+        let start = Instant::now();
+        let outcome = watchdog::run_with_timeout(SOLVE_LIMIT, move || {
+            solution
+                .solve(part, &input)
+                .map(|answer| answer.to_string())
+        });
+        let elapsed = format_micros(start.elapsed().as_secs_f64() * 1e6);
+        match outcome {
+            Ok(Ok(answer)) => format!("Part {}: {} ({})", part, answer, elapsed),
+            Ok(Err(e)) => format!("Part {}: error: {}", part, e),
+            Err(e) => format!("Part {}: {}", part, e),
+        }
+    }
+
+    fn inspect(&self) -> String {
+        let Some(solution) = self.solution else {
+            return String::new();
+        };
+        let input = self.input();
+        match watchdog::run_with_timeout(SOLVE_LIMIT, move || {
+            solution
+                .inspect(&input)
+                .map(|view| view.map_err(|e| e.to_string()))
+        }) {
+            Ok(Some(Ok(view))) => view.trim_end().to_string(),
+            Ok(Some(Err(e))) => format!("error: {}", e),
+            Ok(None) => format!("Day {} has nothing to inspect", solution.day()),
+            Err(e) => e.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions;
+
+    fn session() -> Session {
+        Session::new(solutions::year(2017).unwrap(), PathBuf::from(""))
+    }
+
+    fn text(reply: Reply) -> String {
+        match reply {
+            Reply::Text(text) => text,
+            Reply::Quit => panic!("unexpected quit"),
+        }
+    }
+
+    #[test]
+    fn test_edit_and_run() {
+        let mut session = session();
+        assert!(text(session.execute("1")).starts_with("No day loaded"));
+        assert!(text(session.execute("day 1")).starts_with("Day 1: Inverse Captcha"));
+        assert_eq!(session.prompt(), "day01> ");
+
+        session.execute("clear");
+        session.execute("add 1122");
+        assert!(text(session.execute("1")).starts_with("Part 1: 3 ("));
+        session.execute("set 1 91212129");
+        assert!(text(session.execute("part1")).starts_with("Part 1: 9 ("));
+        assert!(text(session.execute("set 2 1")).starts_with("Usage"));
+        assert_eq!(text(session.execute("input")), "1  91212129");
+        session.execute("del 1");
+        assert_eq!(session.input(), "");
+        assert_eq!(session.execute("quit"), Reply::Quit);
+    }
+
+    #[test]
+    fn test_paste_and_inspect() {
+        let mut session = session();
+        session.execute("day 12");
+        session.execute("paste");
+        assert_eq!(session.prompt(), "> ");
+        for line in ["0 <-> 1", "1 <-> 0", "2 <-> 2"] {
+            session.execute(line);
+        }
+        assert_eq!(text(session.execute(".")), "3 lines");
+        assert_eq!(
+            text(session.execute("inspect")),
+            "2 groups\n2 programs: 0, 1\n1 programs: 2"
+        );
+        assert!(text(session.execute("2")).starts_with("Part 2: 2 ("));
+
+        session.execute("day 1");
+        assert_eq!(
+            text(session.execute("inspect")),
+            "Day 1 has nothing to inspect"
+        );
+    }
+
+    #[test]
+    fn test_load_file() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let path = std::env::temp_dir().join(format!("aoc-repl-test-{}.gz", std::process::id()));
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"1122\n1234\n").unwrap();
+        std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let mut session = session();
+        session.execute("day 1");
+        let reply = text(session.execute(&format!("load {}", path.display())));
+        assert_eq!(reply, "2 lines");
+        assert_eq!(session.input(), "1122\n1234\n");
+        std::fs::remove_file(&path).unwrap();
+
+        let reply = text(session.execute("load /nonexistent/input.txt"));
+        assert!(reply.starts_with("Failed to load input: no input found"));
+    }
+
+    #[test]
+    fn test_examples() {
+        let mut session = session();
+        session.execute("day 6");
+        assert!(text(session.execute("example")).starts_with("1. part 1 → 5"));
+        assert!(text(session.execute("example 2")).starts_with("Example 2: part 2 should give 4"));
+        assert!(text(session.execute("2")).starts_with("Part 2: 4 ("));
+    }
+}
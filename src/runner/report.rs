@@ -0,0 +1,340 @@
+// Result reporting for the runner in human-readable or machine-readable form
+
+use super::bench::format_micros;
+use super::memory::{format_bytes, MemoryStats};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Decorated text for humans
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+/// Outcome of running a single part of a day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartRecord {
+    pub day: u32,
+    pub part: u32,
+    pub title: String,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub time_us: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct: Option<bool>,
+    #[serde(flatten)]
+    pub memory: Option<MemoryStats>,
+    /// Answer and time were taken from the answer cache
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cached: bool,
+}
+
+impl PartRecord {
+    pub fn new(
+        day: u32,
+        part: u32,
+        title: &str,
+        result: Result<String, String>,
+        elapsed: Duration,
+    ) -> Self {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            day,
+            part,
+            title: title.to_string(),
+            answer,
+            error,
+            time_us: elapsed.as_micros() as u64,
+            expected: None,
+            correct: None,
+            memory: None,
+            cached: false,
+        }
+    }
+
+    /// Record the expected answer and whether the actual answer matches it
+    pub fn with_expected(mut self, expected: Option<&str>) -> Self {
+        if let Some(expected) = expected {
+            self.correct = Some(self.answer.as_deref() == Some(expected));
+            self.expected = Some(expected.to_string());
+        }
+        self
+    }
+
+    /// Attach the memory usage measured while running the part
+    pub fn with_memory(mut self, memory: Option<MemoryStats>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Mark the record as served from the answer cache
+    pub fn from_cache(mut self) -> Self {
+        self.cached = true;
+        self
+    }
+
+    /// Time taken, followed by memory usage when it was measured
+    fn cost(&self) -> String {
+        if self.cached {
+            return format!("cached, {}µs", self.time_us);
+        }
+        match &self.memory {
+            Some(memory) => format!(
+                "{}µs, peak {}, {} in {} allocations",
+                self.time_us,
+                format_bytes(memory.peak_bytes),
+                format_bytes(memory.total_bytes),
+                memory.allocations
+            ),
+            None => format!("{}µs", self.time_us),
+        }
+    }
+
+    /// True unless the part failed or produced a wrong answer
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none() && self.correct != Some(false)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("record serialization cannot fail")
+    }
+
+    pub fn to_csv(&self) -> String {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(&self.title),
+            csv_field(self.answer.as_deref().unwrap_or("")),
+            csv_field(self.error.as_deref().unwrap_or("")),
+            self.time_us.to_string(),
+            csv_field(self.expected.as_deref().unwrap_or("")),
+            self.correct.map(|c| c.to_string()).unwrap_or_default(),
+            memory_field(self.memory, |m| m.peak_bytes),
+            memory_field(self.memory, |m| m.total_bytes),
+            memory_field(self.memory, |m| m.allocations),
+            self.cached.to_string(),
+        ]
+        .join(",")
+    }
+}
+
+/// Header row matching [`PartRecord::to_csv`]
+pub const CSV_HEADER: &str =
+    "day,part,title,answer,error,time_us,expected,correct,peak_bytes,total_bytes,allocations,cached";
+
+fn memory_field(memory: Option<MemoryStats>, field: fn(&MemoryStats) -> u64) -> String {
+    memory
+        .as_ref()
+        .map(field)
+        .map(|v| v.to_string())
+        .unwrap_or_default()
+}
+
+/// Quote a CSV field if it contains separators, quotes or newlines
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Prints run results in the selected format
+pub struct Reporter {
+    format: OutputFormat,
+    quiet: bool,
+}
+
+impl Reporter {
+    pub fn new(format: OutputFormat, quiet: bool) -> Self {
+        Self { format, quiet }
+    }
+
+    fn is_text(&self) -> bool {
+        self.format == OutputFormat::Text && !self.quiet
+    }
+
+    /// Print the run banner (text) or header row (CSV)
+    pub fn begin(&self, banner: &str) {
+        match self.format {
+            OutputFormat::Text if !self.quiet => println!("🎄 {} 🎄\n", banner),
+            OutputFormat::Csv => println!("{}", CSV_HEADER),
+            _ => {}
+        }
+    }
+
+    /// Print the results of all parts run for one day
+    pub fn day(&self, day: u32, title: &str, records: &[PartRecord]) {
+        match self.format {
+            OutputFormat::Text if self.quiet => {
+                for record in records {
+                    match (&record.answer, &record.error) {
+                        (Some(answer), _) if record.correct == Some(false) => println!(
+                            "{} (expected {})",
+                            answer,
+                            record.expected.as_deref().unwrap_or("")
+                        ),
+                        (Some(answer), _) => println!("{}", answer),
+                        (None, Some(error)) => {
+                            eprintln!("day {}, part {}: {}", day, record.part, error)
+                        }
+                        (None, None) => {}
+                    }
+                }
+            }
+            OutputFormat::Text => {
+                println!("📅 Day {}: {}", day, title);
+                for record in records {
+                    match (&record.answer, &record.error) {
+                        (Some(answer), _) => match (&record.expected, record.correct) {
+                            (Some(expected), Some(false)) => println!(
+                                "  ❌ Part {}: {} (expected {}) ({})",
+                                record.part,
+                                answer,
+                                expected,
+                                record.cost()
+                            ),
+                            (_, Some(true)) => {
+                                println!("  Part {}: {} ✔ ({})", record.part, answer, record.cost())
+                            }
+                            _ => println!("  Part {}: {} ({})", record.part, answer, record.cost()),
+                        },
+                        (None, Some(error)) => {
+                            println!("  ❌ Part {}: day {}, {}", record.part, day, error)
+                        }
+                        (None, None) => {}
+                    }
+                }
+                if records.iter().all(PartRecord::is_ok) {
+                    println!("  ✅ Day {} completed!\n", day);
+                } else {
+                    println!();
+                }
+            }
+            OutputFormat::Json => records.iter().for_each(|r| println!("{}", r.to_json())),
+            OutputFormat::Csv => records.iter().for_each(|r| println!("{}", r.to_csv())),
+        }
+    }
+
+    /// Report a day that has no solution
+    pub fn missing(&self, day: u32) {
+        if self.is_text() {
+            println!("❌ Day {} not implemented yet", day);
+        } else {
+            eprintln!("Day {} not implemented yet", day);
+        }
+    }
+
+    /// Print total wall-clock time next to the time summed over all parts
+    pub fn timing(&self, wall: Duration, cpu: Duration) {
+        if self.is_text() {
+            let speedup = cpu.as_secs_f64() / wall.as_secs_f64().max(f64::EPSILON);
+            println!(
+                "⏱️  Wall clock {}, CPU time across parts {} ({:.1}x)",
+                format_micros(wall.as_secs_f64() * 1e6),
+                format_micros(cpu.as_secs_f64() * 1e6),
+                speedup
+            );
+        }
+    }
+
+    /// Print the closing line of a successful multi-day run
+    pub fn finish(&self) {
+        if self.is_text() {
+            println!("\n✅ All solutions completed!");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(result: Result<&str, &str>) -> PartRecord {
+        PartRecord::new(
+            7,
+            1,
+            "Recursive Circus",
+            result.map(str::to_string).map_err(str::to_string),
+            Duration::from_micros(1102),
+        )
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            record(Ok("eqgvf")).to_json(),
+            r#"{"day":7,"part":1,"title":"Recursive Circus","answer":"eqgvf","error":null,"time_us":1102}"#
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            record(Ok("eqgvf")).to_csv(),
+            "7,1,Recursive Circus,eqgvf,,1102,,,,,,false"
+        );
+        assert_eq!(
+            record(Err("line 1, token 'x': \"bad\"")).to_csv(),
+            "7,1,Recursive Circus,,\"line 1, token 'x': \"\"bad\"\"\",1102,,,,,,false"
+        );
+    }
+
+    #[test]
+    fn test_with_expected() {
+        let passed = record(Ok("eqgvf")).with_expected(Some("eqgvf"));
+        assert!(passed.is_ok());
+        assert!(passed
+            .to_json()
+            .ends_with(r#""expected":"eqgvf","correct":true}"#));
+
+        let failed = record(Ok("tknk")).with_expected(Some("eqgvf"));
+        assert!(!failed.is_ok());
+        assert_eq!(
+            failed.to_csv(),
+            "7,1,Recursive Circus,tknk,,1102,eqgvf,false,,,,false"
+        );
+
+        assert!(record(Ok("tknk")).with_expected(None).is_ok());
+    }
+
+    #[test]
+    fn test_with_memory() {
+        let measured = record(Ok("eqgvf")).with_memory(Some(MemoryStats {
+            peak_bytes: 2048,
+            total_bytes: 4096,
+            allocations: 3,
+        }));
+        assert!(measured
+            .to_json()
+            .ends_with(r#""peak_bytes":2048,"total_bytes":4096,"allocations":3}"#));
+        assert!(measured.to_csv().ends_with(",2048,4096,3,false"));
+        assert_eq!(
+            measured.cost(),
+            "1102µs, peak 2.0 KiB, 4.0 KiB in 3 allocations"
+        );
+    }
+
+    #[test]
+    fn test_from_cache() {
+        let cached = record(Ok("eqgvf")).from_cache();
+        assert!(cached
+            .to_json()
+            .ends_with(r#""time_us":1102,"cached":true}"#));
+        assert!(cached.to_csv().ends_with(",1102,,,,,,true"));
+        assert_eq!(cached.cost(), "cached, 1102µs");
+        assert!(!record(Ok("eqgvf")).to_json().contains("cached"));
+    }
+}
@@ -0,0 +1,76 @@
+// Expected-answer manifests used by the runner's --verify mode
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Known answers for one day
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ExpectedAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Known answers for every day, keyed by day number
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerManifest {
+    days: BTreeMap<u32, ExpectedAnswers>,
+}
+
+impl AnswerManifest {
+    pub fn parse(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            anyhow::anyhow!("Failed to read answers manifest {}: {}", path.display(), e)
+        })?;
+        Self::parse(&text)
+            .map_err(|e| anyhow::anyhow!("Invalid answers manifest {}: {}", path.display(), e))
+    }
+
+    /// The manifest built into the binary for a year, if the year exists
+    pub fn embedded(year: u16) -> Option<Self> {
+        let answers = crate::solutions::year(year)?.answers;
+        Some(Self::parse(answers).expect("embedded answers manifest is valid"))
+    }
+
+    /// Expected answer for a day and part, if one is recorded
+    pub fn expected(&self, day: u32, part: u32) -> Option<&str> {
+        let answers = self.days.get(&day)?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let manifest = AnswerManifest::parse(
+            r#"{ "1": { "part1": "1251", "part2": "1244" }, "25": { "part1": "2725" } }"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.expected(1, 1), Some("1251"));
+        assert_eq!(manifest.expected(1, 2), Some("1244"));
+        assert_eq!(manifest.expected(25, 2), None);
+        assert_eq!(manifest.expected(2, 1), None);
+        assert!(AnswerManifest::parse(r#"{ "x": {} }"#).is_err());
+    }
+
+    #[test]
+    fn test_embedded_manifest() {
+        let manifest = AnswerManifest::embedded(2017).unwrap();
+        for day in 1..=25 {
+            assert!(manifest.expected(day, 1).is_some(), "day {} part 1", day);
+        }
+        assert_eq!(manifest.expected(7, 1), Some("eqgvf"));
+    }
+}
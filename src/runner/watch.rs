@@ -0,0 +1,141 @@
+// Watch mode: notice edits to a day's files and compare answers between runs
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Modification times of the files in a day's directory
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Snapshot {
+    /// Record every regular file directly inside `dir`; a missing directory is empty
+    pub fn take(dir: &Path) -> Self {
+        let files = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                metadata
+                    .is_file()
+                    .then(|| Some((entry.path(), metadata.modified().ok()?)))
+                    .flatten()
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Files added, removed or modified since `earlier`
+    pub fn changed_since(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|(path, time)| earlier.files.get(*path) != Some(time))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            earlier
+                .files
+                .keys()
+                .filter(|path| !self.files.contains_key(*path))
+                .cloned(),
+        );
+        changed
+    }
+}
+
+/// Outcome of each part, keyed by part number
+pub type Answers = BTreeMap<u32, Result<String, String>>;
+
+/// Read the part records printed by `--format json`
+pub fn parse_answers(json_lines: &str) -> Answers {
+    json_lines
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|record| {
+            let part = u32::try_from(record.get("part")?.as_u64()?).ok()?;
+            let text = |field: &str| record.get(field)?.as_str().map(str::to_string);
+            let outcome = match (text("answer"), text("error")) {
+                (Some(answer), _) => Ok(answer),
+                (None, error) => Err(error.unwrap_or_else(|| "no answer".to_string())),
+            };
+            Some((part, outcome))
+        })
+        .collect()
+}
+
+/// One line per part describing how its answer changed since the previous run
+pub fn diff_answers(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    let describe = |outcome: &Result<String, String>| match outcome {
+        Ok(answer) => answer.clone(),
+        Err(error) => format!("error: {}", error),
+    };
+
+    current
+        .iter()
+        .map(|(part, outcome)| {
+            let now = describe(outcome);
+            match previous.and_then(|p| p.get(part)) {
+                None => format!("Part {}: {}", part, now),
+                Some(before) if before == outcome => format!("Part {}: {} (unchanged)", part, now),
+                Some(before) => format!("Part {}: {} → {} (changed)", part, describe(before), now),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let output = concat!(
+            r#"{"day":5,"part":1,"title":"t","answer":"388611","error":null,"time_us":900}"#,
+            "\n",
+            r#"{"day":5,"part":2,"title":"t","answer":null,"error":"line 1: bad","time_us":3}"#,
+            "\nnot json\n"
+        );
+        let answers = parse_answers(output);
+        assert_eq!(answers[&1], Ok("388611".to_string()));
+        assert_eq!(answers[&2], Err("line 1: bad".to_string()));
+        assert_eq!(answers.len(), 2);
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let before: Answers = [(1, Ok("10".to_string())), (2, Ok("20".to_string()))].into();
+        let after: Answers = [(1, Ok("10".to_string())), (2, Err("boom".to_string()))].into();
+
+        assert_eq!(diff_answers(None, &before), ["Part 1: 10", "Part 2: 20"]);
+        assert_eq!(
+            diff_answers(Some(&before), &after),
+            [
+                "Part 1: 10 (unchanged)",
+                "Part 2: 20 → error: boom (changed)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let empty = Snapshot::take(&dir);
+
+        let file = dir.join("input.txt");
+        std::fs::write(&file, "1").unwrap();
+        let written = Snapshot::take(&dir);
+        assert_eq!(written.changed_since(&empty), std::slice::from_ref(&file));
+        assert!(written.changed_since(&written).is_empty());
+
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(Snapshot::take(&dir).changed_since(&written), [file]);
+
+        std::fs::remove_dir(&dir).unwrap();
+        assert_eq!(Snapshot::take(&dir), Snapshot::default());
+    }
+}
@@ -0,0 +1,75 @@
+// Watchdog that stops waiting for solvers which run too long
+
+use std::fmt;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Reason a watched solver did not produce a result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchdogError {
+    TimedOut(Duration),
+    Panicked,
+}
+
+impl fmt::Display for WatchdogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WatchdogError::TimedOut(limit) => write!(f, "timed out after {}s", limit.as_secs_f64()),
+            WatchdogError::Panicked => write!(f, "solver panicked"),
+        }
+    }
+}
+
+impl std::error::Error for WatchdogError {}
+
+/// Run `work` on a worker thread and wait at most `limit` for it to finish
+///
+/// Threads cannot be cancelled, so a timed-out worker is detached and keeps
+/// running in the background until the process exits.
+pub fn run_with_timeout<R, F>(limit: Duration, work: F) -> Result<R, WatchdogError>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name("solver".to_string())
+        .spawn(move || {
+            // The receiver is gone if we already gave up waiting
+            let _ = sender.send(work());
+        })
+        .expect("failed to spawn solver thread");
+
+    match receiver.recv_timeout(limit) {
+        Ok(result) => Ok(result),
+        Err(RecvTimeoutError::Timeout) => Err(WatchdogError::TimedOut(limit)),
+        Err(RecvTimeoutError::Disconnected) => Err(WatchdogError::Panicked),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finishes_in_time() {
+        assert_eq!(run_with_timeout(Duration::from_secs(5), || 42), Ok(42));
+    }
+
+    #[test]
+    fn test_times_out() {
+        let result = run_with_timeout(Duration::from_millis(50), || loop {
+            thread::sleep(Duration::from_millis(10));
+        });
+        let error: WatchdogError = result.unwrap_err();
+        assert_eq!(error, WatchdogError::TimedOut(Duration::from_millis(50)));
+        assert_eq!(error.to_string(), "timed out after 0.05s");
+    }
+
+    #[test]
+    fn test_panicked() {
+        let result = run_with_timeout(Duration::from_secs(5), || -> u32 { panic!("boom") });
+        assert_eq!(result, Err(WatchdogError::Panicked));
+    }
+}
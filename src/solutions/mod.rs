@@ -1,27 +1,175 @@
-// Solutions module - declares all daily solutions
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+// Solutions module - the Solution trait and the per-year registries the runner uses
+
+use crate::utils::input::ParseError;
+use std::fmt;
+
+pub mod y2017;
+
+/// Unified answer type so every day can be handled the same way
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A single day's puzzle solution
+///
+/// Each `dayNN` module exposes a unit struct implementing this trait,
+/// which is then listed in [`SOLUTIONS`].
+pub trait Solution: Sync {
+    /// Day number (1-25)
+    fn day(&self) -> u32;
+
+    /// Puzzle title, without the "Day N: " prefix
+    fn title(&self) -> &'static str;
+
+    /// Puzzle input embedded into the binary at compile time
+    fn input(&self) -> &'static str;
+
+    /// Solver version, part of the runner's answer-cache key
+    ///
+    /// The key also carries a fingerprint of the sources, so any edit already
+    /// invalidates cached answers; bumping this is only needed to drop them
+    /// without touching the code.
+    fn version(&self) -> u32 {
+        1
+    }
+
+    /// Run only the input-parsing step, so it can be timed apart from solving
+    ///
+    /// Days without a separate parsing step keep the default, which attributes
+    /// all of their work to solving.
+    fn parse(&self, _input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    /// Render the structures the solver builds from the input, for the REPL
+    ///
+    /// Returns `None` for days with nothing interesting to show.
+    fn inspect(&self, _input: &str) -> Option<Result<String, ParseError>> {
+        None
+    }
+
+    /// Solve part 1 for the given input, failing on malformed input
+    fn part1(&self, input: &str) -> Result<Answer, ParseError>;
+
+    /// Solve part 2 for the given input, failing on malformed input
+    fn part2(&self, input: &str) -> Result<Answer, ParseError>;
+
+    /// Solve the given part
+    ///
+    /// Only parts 1 and 2 exist; the runner rejects any other part number
+    /// (via `--part`'s range, the examples parser and the REPL's commands)
+    /// before calling this, so anything else is a bug and panics.
+    fn solve(&self, part: u32, input: &str) -> Result<Answer, ParseError> {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => unreachable!("day {} has no part {}", self.day(), part),
+        }
+    }
+}
+
+/// Solutions and known answers for one event year
+pub struct Year {
+    pub year: u16,
+    /// Implemented solutions, ordered by day
+    pub solutions: &'static [&'static dyn Solution],
+    /// Embedded answers manifest (JSON keyed by day number)
+    pub answers: &'static str,
+}
+
+impl Year {
+    /// Look up the solution for a given day
+    pub fn get(&self, day: u32) -> Option<&'static dyn Solution> {
+        self.solutions.iter().copied().find(|s| s.day() == day)
+    }
+}
+
+/// Every year with solutions
+pub static YEARS: &[&Year] = &[&y2017::YEAR];
+
+/// Look up a year's solutions
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().copied().find(|y| y.year == year)
+}
+
+/// Most recent year with solutions
+pub fn latest_year() -> u16 {
+    YEARS
+        .iter()
+        .map(|y| y.year)
+        .max()
+        .unwrap_or(aoc_layout::DEFAULT_YEAR)
+}
+
+/// Look up the solution for a given year and day
+pub fn get(year_number: u16, day: u32) -> Option<&'static dyn Solution> {
+    year(year_number)?.get(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(2017, 7).map(|s| s.title()), Some("Recursive Circus"));
+        assert!(get(2017, 0).is_none());
+        assert!(get(2017, 26).is_none());
+        assert!(get(2014, 1).is_none());
+        assert_eq!(latest_year(), 2017);
+    }
+
+    #[test]
+    #[should_panic(expected = "day 1 has no part 3")]
+    fn test_solve_rejects_unknown_part() {
+        let _ = get(2017, 1).unwrap().solve(3, "1122");
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(1251u32).to_string(), "1251");
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from("tknk").to_string(), "tknk");
+    }
+}
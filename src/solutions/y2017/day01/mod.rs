@@ -1,6 +1,9 @@
 // Day 1: Inverse Captcha
 // https://adventofcode.com/2017/day/1
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::ParseError;
+
 /// Solve part 1: Sum of digits that match the next digit (circular)
 pub fn solve_part1(input: &str) -> u32 {
     let digits: Vec<u32> = input
@@ -52,6 +55,30 @@ pub fn solve_part2(input: &str) -> u32 {
     sum
 }
 
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Inverse Captcha"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,8 +101,8 @@ mod tests {
 
     #[test]
     fn test_part1_input() {
-        let input =
-            input::read_input("src/solutions/day01/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day01/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part1(&input);
         assert_eq!(answer, 1251);
     }
@@ -100,8 +127,8 @@ mod tests {
 
     #[test]
     fn test_part2_input() {
-        let input =
-            input::read_input("src/solutions/day01/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day01/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part2(&input);
         assert_eq!(answer, 1244);
     }
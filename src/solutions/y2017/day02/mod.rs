@@ -1,21 +1,20 @@
 // Day 2: Corruption Checksum
 // https://adventofcode.com/2017/day/2
 
-/// Parse a row of whitespace-separated numbers
-fn parse_row(row: &str) -> Vec<u32> {
-    row.split_whitespace()
-        .filter_map(|s| s.parse().ok())
-        .collect()
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, ParseError};
+
+/// Parse the spreadsheet into rows of whitespace-separated numbers
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    input::parse_each_line(input, |line| line.words(line.text, "number"))
 }
 
 /// Solve part 1: Calculate checksum by summing differences between max and min of each row
-pub fn solve_part1(input: &str) -> u32 {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let numbers = parse_row(line);
+pub fn try_solve_part1(input: &str) -> Result<u32, ParseError> {
+    let rows = parse_input(input)?;
+    Ok(rows
+        .iter()
+        .map(|numbers| {
             if numbers.is_empty() {
                 0
             } else {
@@ -24,19 +23,21 @@ pub fn solve_part1(input: &str) -> u32 {
                 max - min
             }
         })
-        .sum()
+        .sum())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> u32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
 }
 
 /// Solve part 2: Find the two numbers in each row where one evenly divides the other
 /// and sum the division results
-pub fn solve_part2(input: &str) -> u32 {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let numbers = parse_row(line);
-
+pub fn try_solve_part2(input: &str) -> Result<u32, ParseError> {
+    let rows = parse_input(input)?;
+    Ok(rows
+        .iter()
+        .map(|numbers| {
             // Find the pair where one evenly divides the other
             for i in 0..numbers.len() {
                 for j in i + 1..numbers.len() {
@@ -62,7 +63,40 @@ pub fn solve_part2(input: &str) -> u32 {
             // Should not reach here based on problem statement
             0
         })
-        .sum()
+        .sum())
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> u32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Corruption Checksum"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -77,7 +111,7 @@ mod tests {
 2 4 6 8";
 
         // Test individual rows
-        assert_eq!(parse_row("5 1 9 5"), vec![5, 1, 9, 5]);
+        assert_eq!(parse_input("5 1 9 5").unwrap(), [vec![5, 1, 9, 5]]);
 
         // The first row's difference is 9 - 1 = 8
         // The second row's difference is 7 - 3 = 4
@@ -86,10 +120,17 @@ mod tests {
         assert_eq!(solve_part1(example), 18);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("5 1 9 5\n7 x 3").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "invalid number");
+    }
+
     #[test]
     fn test_part1_input() {
-        let input =
-            input::read_input("src/solutions/day02/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day02/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part1(&input);
         assert_eq!(answer, 51139);
     }
@@ -109,8 +150,8 @@ mod tests {
 
     #[test]
     fn test_part2_input() {
-        let input =
-            input::read_input("src/solutions/day02/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day02/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part2(&input);
         assert_eq!(answer, 272);
     }
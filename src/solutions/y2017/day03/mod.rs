@@ -1,13 +1,22 @@
 // Day 3: Spiral Memory
 // https://adventofcode.com/2017/day/3
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::grid::{Direction, Point, SparseGrid};
+use crate::utils::input::ParseError;
+
 /// Solve part 1: Calculate Manhattan distance from a given square to the center
-pub fn solve_part1(input: &str) -> i32 {
-    let n = input.trim().parse::<i32>().expect("Invalid input");
+pub fn try_solve_part1(input: &str) -> Result<i32, ParseError> {
+    let token = input.trim();
+    let n = token
+        .parse::<i32>()
+        .ok()
+        .filter(|&n| n >= 1)
+        .ok_or_else(|| ParseError::at(1, input, token, "expected a positive square number"))?;
 
     // Special case: center square
     if n == 1 {
-        return 0;
+        return Ok(0);
     }
 
     // Find which "ring" the number is in
@@ -48,64 +57,55 @@ pub fn solve_part1(input: &str) -> i32 {
     let distance_from_middle = (position_in_side - (side_length / 2 - 1)).abs();
 
     // Manhattan distance is ring distance + distance from middle of side
-    ring + distance_from_middle
+    Ok(ring + distance_from_middle)
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> i32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
 }
 
 /// Solve part 2: Find the first value in the spiral that is larger than the input
 /// where each value is the sum of all adjacent values (including diagonals)
-pub fn solve_part2(input: &str) -> i32 {
-    let target = input.trim().parse::<i64>().expect("Invalid input");
-
-    use std::collections::HashMap;
-
-    // Store values at each position
-    let mut grid: HashMap<(i32, i32), i64> = HashMap::new();
-
-    // Start at center with value 1
-    grid.insert((0, 0), 1);
-
-    // Direction vectors: right, up, left, down
-    let directions = [(1, 0), (0, 1), (-1, 0), (0, -1)];
-    let mut dir_idx = 0;
-
-    let mut x = 0;
-    let mut y = 0;
+pub fn try_solve_part2(input: &str) -> Result<i32, ParseError> {
+    let token = input.trim();
+    let target = token
+        .parse::<i64>()
+        .ok()
+        .filter(|&target| target >= 1)
+        .ok_or_else(|| ParseError::at(1, input, token, "expected a positive target value"))?;
+
+    // Values written so far; unwritten squares count as 0
+    let mut grid = SparseGrid::new(0i64);
+
+    // Start at center with value 1, then spiral out anticlockwise
+    grid.set(Point::ORIGIN, 1);
+    let mut position = Point::ORIGIN;
+    let mut direction = Direction::Right;
     let mut steps_in_direction = 1;
     let mut steps_taken = 0;
     let mut times_turned = 0;
 
     loop {
         // Move in current direction
-        let (dx, dy) = directions[dir_idx];
-        x += dx;
-        y += dy;
+        position += direction;
 
         // Calculate value as sum of all adjacent cells (including diagonals)
-        let mut value: i64 = 0;
-        for adj_x in -1..=1 {
-            for adj_y in -1..=1 {
-                if adj_x == 0 && adj_y == 0 {
-                    continue;
-                }
-                if let Some(&adj_val) = grid.get(&(x + adj_x, y + adj_y)) {
-                    value += adj_val;
-                }
-            }
-        }
+        let value: i64 = position.neighbors8().iter().map(|&p| *grid.get(p)).sum();
 
         // If this is the first value larger than target, return it
         if value > target {
-            return value as i32;
+            return Ok(value as i32);
         }
 
-        grid.insert((x, y), value);
+        grid.set(position, value);
 
         steps_taken += 1;
 
         // Check if we need to turn
         if steps_taken == steps_in_direction {
             steps_taken = 0;
-            dir_idx = (dir_idx + 1) % 4;
+            direction = direction.turn_left();
             times_turned += 1;
 
             // Increase steps after every 2 turns (completing a "ring")
@@ -116,6 +116,35 @@ pub fn solve_part2(input: &str) -> i32 {
     }
 }
 
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> i32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Spiral Memory"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +181,14 @@ mod tests {
         let result = solve_part2(input);
         assert_eq!(result, 295229);
     }
+
+    #[test]
+    fn test_parse_error() {
+        for input in ["0", "-5", "x"] {
+            let err = try_solve_part1(input).unwrap_err();
+            assert_eq!(err.message, "expected a positive square number");
+            assert_eq!(err.token, input);
+            assert!(try_solve_part2(input).is_err());
+        }
+    }
 }
@@ -1,6 +1,8 @@
 // Day 4: High-Entropy Passphrases
 // https://adventofcode.com/2017/day/4
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::ParseError;
 use std::collections::HashSet;
 
 /// Solve part 1: Count valid passphrases (no duplicate words)
@@ -49,6 +51,30 @@ fn is_valid_passphrase_no_anagrams(passphrase: &str) -> bool {
     words.len() == unique_words.len()
 }
 
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "High-Entropy Passphrases"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
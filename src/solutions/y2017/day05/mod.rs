@@ -1,18 +1,17 @@
 // Day 5: A Maze of Twisty Trampolines, All Alike
 // https://adventofcode.com/2017/day/5
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, ParseError};
+
 /// Parse the input into a vector of jump offsets
-fn parse_input(input: &str) -> Vec<i32> {
-    input
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .map(|line| line.trim().parse::<i32>().expect("Invalid number in input"))
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    input::parse_each_line(input, |line| line.parse(line.text.trim(), "jump offset"))
 }
 
 /// Solve part 1: Count steps to exit the jump maze
-pub fn solve_part1(input: &str) -> u32 {
-    let mut jumps = parse_input(input);
+pub fn try_solve_part1(input: &str) -> Result<u32, ParseError> {
+    let mut jumps = parse_input(input)?;
     let len = jumps.len() as i32;
     let mut position: i32 = 0;
     let mut steps = 0;
@@ -31,12 +30,17 @@ pub fn solve_part1(input: &str) -> u32 {
         steps += 1;
     }
 
-    steps
+    Ok(steps)
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> u32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
 }
 
 /// Solve part 2: Count steps with modified increment rule
-pub fn solve_part2(input: &str) -> u32 {
-    let mut jumps = parse_input(input);
+pub fn try_solve_part2(input: &str) -> Result<u32, ParseError> {
+    let mut jumps = parse_input(input)?;
     let len = jumps.len() as i32;
     let mut position: i32 = 0;
     let mut steps = 0;
@@ -59,7 +63,40 @@ pub fn solve_part2(input: &str) -> u32 {
         steps += 1;
     }
 
-    steps
+    Ok(steps)
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> u32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "A Maze of Twisty Trampolines, All Alike"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -82,6 +119,13 @@ mod tests {
         assert_eq!(solve_part1(input), 388611);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("0\n3\n  x1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.token, "x1");
+    }
+
     #[test]
     fn test_part2_examples() {
         let example = "0
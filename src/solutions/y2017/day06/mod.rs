@@ -1,14 +1,20 @@
 // Day 6: Memory Reallocation
 // https://adventofcode.com/2017/day/6
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, Line, ParseError};
 use std::collections::HashSet;
 
 /// Parse the input into a vector of memory bank values
-fn parse_input(input: &str) -> Vec<usize> {
-    input
-        .split_whitespace()
-        .filter_map(|s| s.parse().ok())
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = input::numbered_lines(input)
+        .next()
+        .unwrap_or(Line::new(1, ""));
+    let banks: Vec<usize> = line.words(line.text, "block count")?;
+    if banks.is_empty() {
+        return Err(line.error(line.text, "expected memory banks"));
+    }
+    Ok(banks)
 }
 
 /// Perform one redistribution cycle
@@ -36,8 +42,8 @@ fn redistribute(banks: &mut [usize]) {
 }
 
 /// Solve part 1: Count redistribution cycles until a repeated configuration is seen
-pub fn solve_part1(input: &str) -> usize {
-    let mut banks = parse_input(input);
+pub fn try_solve_part1(input: &str) -> Result<usize, ParseError> {
+    let mut banks = parse_input(input)?;
     let mut seen_states = HashSet::new();
     let mut cycles = 0;
 
@@ -50,14 +56,19 @@ pub fn solve_part1(input: &str) -> usize {
 
         // Check if we've seen this state before
         if !seen_states.insert(banks.clone()) {
-            return cycles;
+            return Ok(cycles);
         }
     }
 }
 
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> usize {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
 /// Solve part 2: Size of the loop (cycles between repeated states)
-pub fn solve_part2(input: &str) -> usize {
-    let mut banks = parse_input(input);
+pub fn try_solve_part2(input: &str) -> Result<usize, ParseError> {
+    let mut banks = parse_input(input)?;
     let mut seen_states = HashSet::new();
 
     // First, find the repeated state
@@ -79,11 +90,44 @@ pub fn solve_part2(input: &str) -> usize {
         cycles += 1;
 
         if banks == target_state {
-            return cycles;
+            return Ok(cycles);
         }
     }
 }
 
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> usize {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Memory Reallocation"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,10 +140,18 @@ mod tests {
         assert_eq!(solve_part1("0 2 7 0"), 5);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("0 2 x 0").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.message, "invalid block count");
+        assert!(try_solve_part2("\n").is_err());
+    }
+
     #[test]
     fn test_part1_input() {
-        let input =
-            input::read_input("src/solutions/day06/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day06/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part1(&input);
         assert_eq!(answer, 14029);
     }
@@ -113,8 +165,8 @@ mod tests {
 
     #[test]
     fn test_part2_input() {
-        let input =
-            input::read_input("src/solutions/day06/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day06/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part2(&input);
         assert_eq!(answer, 2765);
     }
@@ -0,0 +1,252 @@
+// Day 7: Recursive Circus
+// https://adventofcode.com/2017/day/7
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{Line, ParseError};
+use crate::utils::tree::{NodeId, Tree};
+use std::collections::{HashMap, HashSet};
+
+/// Parse a line like `fwft (72) -> ktlj, cntj, xhth` into name, weight and children
+fn parse_line<'a>(line: Line<'a>) -> Result<(&'a str, i32, Vec<&'a str>), ParseError> {
+    let (name_weight, children) = line.text.split_once(" -> ").unwrap_or((line.text, ""));
+
+    // Parse name and weight
+    let (name, weight_str) = name_weight
+        .split_once(' ')
+        .ok_or_else(|| line.error(name_weight, "expected 'name (weight)'"))?;
+    let weight = weight_str
+        .strip_prefix('(')
+        .and_then(|w| w.strip_suffix(')'))
+        .and_then(|w| w.parse().ok())
+        .ok_or_else(|| line.error(weight_str, "invalid weight"))?;
+
+    // Parse children if they exist
+    let children = children.split(", ").filter(|c| !c.is_empty()).collect();
+
+    Ok((name, weight, children))
+}
+
+/// The tower of programs, with each program's own weight
+fn build_tree(input: &str) -> Result<Tree<String, i32>, ParseError> {
+    Tree::parse(input, parse_line)
+}
+
+/// Solve part 1: Find the name of the bottom program (root of the tree)
+///
+/// Approach: The root program is the only one that isn't held by any other program.
+pub fn try_solve_part1(input: &str) -> Result<String, ParseError> {
+    let tree = build_tree(input)?;
+    Ok(tree.key(tree.root()).clone())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> String {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Total weight of every tower (program + all its descendants), indexed by node
+fn tower_weights(tree: &Tree<String, i32>) -> Vec<i32> {
+    tree.aggregate(|id, children| tree.value(id) + children.iter().copied().sum::<i32>())
+}
+
+/// Find the unbalanced node and return the corrected weight
+fn find_unbalanced(tree: &Tree<String, i32>, totals: &[i32], node: NodeId) -> Option<i32> {
+    let children = tree.children(node);
+
+    // Group children by tower weight to find the odd one out
+    let mut weight_counts: HashMap<i32, Vec<NodeId>> = HashMap::new();
+    for &child in children {
+        weight_counts.entry(totals[child]).or_default().push(child);
+    }
+
+    // If all weights are the same, this disc is balanced
+    if weight_counts.len() <= 1 {
+        // Check children recursively
+        return children
+            .iter()
+            .find_map(|&child| find_unbalanced(tree, totals, child));
+    }
+
+    // Find the incorrect weight (the one that appears only once)
+    let (&incorrect_weight, incorrect_nodes) =
+        weight_counts.iter().find(|(_, nodes)| nodes.len() == 1)?;
+    let incorrect_node = incorrect_nodes[0];
+
+    // Find the correct weight (the one that appears multiple times)
+    let correct_weight = *weight_counts.keys().find(|&&w| w != incorrect_weight)?;
+
+    // First check if the problem is deeper in the tree
+    if let Some(result) = find_unbalanced(tree, totals, incorrect_node) {
+        return Some(result);
+    }
+
+    // The problem is with this node itself
+    Some(tree.value(incorrect_node) + correct_weight - incorrect_weight)
+}
+
+/// Solve part 2: Find the weight needed to balance the tower
+///
+/// Approach: Calculate total tower weights for all nodes, then traverse the tree
+/// to find the unbalanced disc. The unbalanced node is the one with children
+/// where one child's tower weight differs from the others.
+pub fn try_solve_part2(input: &str) -> Result<i32, ParseError> {
+    let tree = build_tree(input)?;
+    let totals = tower_weights(&tree);
+
+    // Find the unbalanced node and return the corrected weight
+    Ok(find_unbalanced(&tree, &totals, tree.root()).unwrap_or(0))
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> i32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Draw the tower as a tree of `name (weight) [tower weight]`,
+/// flagging discs whose sub-towers differ in weight
+pub fn render_tower(input: &str) -> Result<String, ParseError> {
+    let tree = build_tree(input)?;
+    let totals = tower_weights(&tree);
+
+    Ok(tree.render_ascii(|id| {
+        let child_totals: HashSet<i32> = tree.children(id).iter().map(|&c| totals[c]).collect();
+        let flag = if child_totals.len() > 1 {
+            " unbalanced"
+        } else {
+            ""
+        };
+        format!(
+            "{} ({}) [{}]{}",
+            tree.key(id),
+            tree.value(id),
+            totals[id],
+            flag
+        )
+    }))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Recursive Circus"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        build_tree(input).map(|_| ())
+    }
+
+    fn inspect(&self, input: &str) -> Option<Result<String, ParseError>> {
+        Some(render_tower(input))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input;
+
+    #[test]
+    fn test_part1_examples() {
+        let example = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+        assert_eq!(solve_part1(example), "tknk");
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = input::read_input("src/solutions/y2017/day07/input.txt")
+            .expect("Failed to read input file");
+        let answer = solve_part1(&input);
+        assert_eq!(answer, "eqgvf");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("pbga (66)\nxhth (5x7)").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.token, "(5x7)");
+        assert!(try_solve_part2("pbga").is_err());
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        let example = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+        assert_eq!(solve_part2(example), 60);
+    }
+
+    #[test]
+    fn test_render_tower() {
+        let example = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+        let tower = render_tower(example).unwrap();
+        let lines: Vec<&str> = tower.lines().collect();
+        assert_eq!(lines.len(), 13);
+        assert_eq!(lines[0], "tknk (41) [778] unbalanced");
+        assert_eq!(lines[1], "|-- ugml (68) [251]");
+        assert_eq!(lines[2], "|   |-- gyxo (61) [61]");
+        assert_eq!(lines[5], "|-- padx (45) [243]");
+        assert_eq!(lines[12], "    `-- xhth (57) [57]");
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = input::read_input("src/solutions/y2017/day07/input.txt")
+            .expect("Failed to read input file");
+        let answer = solve_part2(&input);
+        assert_eq!(answer, 757);
+    }
+}
@@ -0,0 +1,212 @@
+// Day 8: I Heard You Like Registers
+// https://adventofcode.com/2017/day/8
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, Line, ParseError};
+use std::collections::HashMap;
+
+/// Comparison used in an instruction's condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Greater,
+    Less,
+    GreaterOrEqual,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    fn holds(self, left: i32, right: i32) -> bool {
+        match self {
+            Comparison::Greater => left > right,
+            Comparison::Less => left < right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+        }
+    }
+}
+
+/// `target inc|dec amount if register op value`, with `dec` already negated
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Instruction<'a> {
+    target: &'a str,
+    amount: i32,
+    register: &'a str,
+    comparison: Comparison,
+    value: i32,
+}
+
+/// Parse an instruction from a line
+fn parse_instruction(line: Line) -> Result<Instruction, ParseError> {
+    let parts: Vec<&str> = line.text.split_whitespace().collect();
+    let [target, operation, amount, keyword, register, comparison, value] = parts[..] else {
+        return Err(line.error(
+            line.text.trim(),
+            "expected 'register inc|dec amount if register op value'",
+        ));
+    };
+
+    let amount: i32 = line.parse(amount, "amount")?;
+    let amount = match operation {
+        "inc" => amount,
+        "dec" => -amount,
+        _ => return Err(line.error(operation, "expected 'inc' or 'dec'")),
+    };
+
+    if keyword != "if" {
+        return Err(line.error(keyword, "expected 'if'"));
+    }
+
+    let comparison = match comparison {
+        ">" => Comparison::Greater,
+        "<" => Comparison::Less,
+        ">=" => Comparison::GreaterOrEqual,
+        "<=" => Comparison::LessOrEqual,
+        "==" => Comparison::Equal,
+        "!=" => Comparison::NotEqual,
+        _ => return Err(line.error(comparison, "unknown comparison")),
+    };
+
+    Ok(Instruction {
+        target,
+        amount,
+        register,
+        comparison,
+        value: line.parse(value, "value")?,
+    })
+}
+
+/// Parse one instruction per non-empty line
+fn parse_instructions(input: &str) -> Result<Vec<Instruction<'_>>, ParseError> {
+    input::parse_each_line(input, parse_instruction)
+}
+
+/// Run every instruction, returning the largest final value and the highest
+/// value held at any point
+fn run(instructions: &[Instruction]) -> (i32, i32) {
+    let mut registers: HashMap<&str, i32> = HashMap::new();
+    let mut highest_ever = 0;
+
+    for instruction in instructions {
+        // Registers that were never written hold 0
+        let register_value = *registers.get(instruction.register).unwrap_or(&0);
+        if instruction
+            .comparison
+            .holds(register_value, instruction.value)
+        {
+            let entry = registers.entry(instruction.target).or_insert(0);
+            *entry += instruction.amount;
+            highest_ever = highest_ever.max(*entry);
+        }
+    }
+
+    (*registers.values().max().unwrap_or(&0), highest_ever)
+}
+
+/// Solve part 1: Find the largest value in any register after completing all instructions
+pub fn try_solve_part1(input: &str) -> Result<i32, ParseError> {
+    Ok(run(&parse_instructions(input)?).0)
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> i32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Solve part 2: Find the highest value held in any register during the entire process
+pub fn try_solve_part2(input: &str) -> Result<i32, ParseError> {
+    Ok(run(&parse_instructions(input)?).1)
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> i32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "I Heard You Like Registers"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_instructions(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_examples() {
+        let example = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10";
+
+        assert_eq!(solve_part1(example), 1);
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = input::read_input("src/solutions/y2017/day08/input.txt")
+            .expect("Failed to read input file");
+        let answer = solve_part1(&input);
+        assert_eq!(answer, 4902);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        let example = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10";
+
+        assert_eq!(solve_part2(example), 10);
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = input::read_input("src/solutions/y2017/day08/input.txt")
+            .expect("Failed to read input file");
+        let answer = solve_part2(&input);
+        assert_eq!(answer, 7037);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("b inc 5 if a > 1\na inc 5 if b <> 3").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 14));
+        assert_eq!(
+            (err.token.as_str(), err.message.as_str()),
+            ("<>", "unknown comparison")
+        );
+
+        let err = try_solve_part2("a inc 5 if b").unwrap_err();
+        assert_eq!(err.line, 1);
+        assert!(try_solve_part1("a mul 5 if b > 1").is_err());
+        assert!(try_solve_part1("a inc x if b > 1").is_err());
+        assert!(try_solve_part1("a inc 5 when b > 1").is_err());
+    }
+}
@@ -1,6 +1,9 @@
 // Day 9: Stream Processing
 // https://adventofcode.com/2017/day/9
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::ParseError;
+
 /// Process the stream and return the total score of all groups
 /// Approach: Parse character by character, tracking depth and garbage state.
 /// Groups contribute their depth level to the total score when closed.
@@ -108,6 +111,30 @@ pub fn solve_part2(input: &str) -> i32 {
     garbage_count
 }
 
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Stream Processing"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,8 +155,8 @@ mod tests {
 
     #[test]
     fn test_part1_input() {
-        let input =
-            input::read_input("src/solutions/day09/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day09/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part1(&input);
         assert_eq!(answer, 10820);
     }
@@ -148,8 +175,8 @@ mod tests {
 
     #[test]
     fn test_part2_input() {
-        let input =
-            input::read_input("src/solutions/day09/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day09/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part2(&input);
         assert_eq!(answer, 5547);
     }
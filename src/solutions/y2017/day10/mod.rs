@@ -1,20 +1,22 @@
 // Day 10: Knot Hash
 // https://adventofcode.com/2017/day/10
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, Line, ParseError};
+
 /// Parse the input to get the sequence of lengths
-fn parse_input(input: &str) -> Vec<usize> {
-    input
-        .trim()
-        .split(',')
-        .filter_map(|s| s.trim().parse().ok())
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = input::numbered_lines(input)
+        .next()
+        .unwrap_or(Line::new(1, ""));
+    line.separated(line.text, ",", "length")
 }
 
 /// Solve part 1: Implement the Knot Hash algorithm and multiply the first two numbers
 /// Approach: Parse comma-separated lengths, perform knot hash operations on a circular list of 0-255,
 /// reversing subsequences while tracking position and skip size, then return product of first two elements.
-pub fn solve_part1(input: &str) -> u32 {
-    let lengths = parse_input(input);
+pub fn try_solve_part1(input: &str) -> Result<u32, ParseError> {
+    let lengths = parse_input(input)?;
 
     // Initialize the list with numbers from 0 to 255
     let mut list: Vec<u32> = (0..256).collect();
@@ -53,7 +55,12 @@ pub fn solve_part1(input: &str) -> u32 {
     }
 
     // Multiply the first two numbers
-    list[0] * list[1]
+    Ok(list[0] * list[1])
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> u32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
 }
 
 /// Solve part 2: Full Knot Hash with ASCII conversion, 64 rounds, dense hash, and hex output
@@ -63,6 +70,34 @@ pub fn solve_part2(input: &str) -> String {
     crate::utils::hash::knot_hash(input)
 }
 
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Knot Hash"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,7 +108,7 @@ mod tests {
         // For the example, we need to use a smaller list (0-4) instead of 0-255
         // Let's create a specialized test function for this
         fn solve_part1_example(input: &str, list_size: usize) -> u32 {
-            let lengths = parse_input(input);
+            let lengths = parse_input(input).unwrap();
 
             // Initialize the list with numbers from 0 to list_size-1
             let mut list: Vec<u32> = (0..list_size as u32).collect();
@@ -112,10 +147,16 @@ mod tests {
         assert_eq!(solve_part1_example("3,4,1,5", 5), 12);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("3,4,-1,5").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 5, "-1"));
+    }
+
     #[test]
     fn test_part1_input() {
-        let input =
-            input::read_input("src/solutions/day10/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day10/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part1(&input);
         assert_eq!(answer, 6909);
     }
@@ -131,8 +172,8 @@ mod tests {
 
     #[test]
     fn test_part2_input() {
-        let input =
-            input::read_input("src/solutions/day10/input.txt").expect("Failed to read input file");
+        let input = input::read_input("src/solutions/y2017/day10/input.txt")
+            .expect("Failed to read input file");
         let answer = solve_part2(&input);
         assert_eq!(answer, "9d5f4561367d379cfbf04f8c471c0095");
     }
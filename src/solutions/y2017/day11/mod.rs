@@ -0,0 +1,132 @@
+// Day 11: Hex Ed
+// https://adventofcode.com/2017/day/11
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::hex::{Hex, Orientation};
+use crate::utils::input::ParseError;
+
+/// Parse the input into the sequence of unit steps
+fn parse_input(input: &str) -> Result<Vec<Hex>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|s| s.trim())
+        .map(|direction| {
+            Orientation::FlatTop
+                .direction(direction)
+                .ok_or_else(|| ParseError::at(1, input, direction, "invalid direction"))
+        })
+        .collect()
+}
+
+/// Solve part 1: Find the fewest number of steps to reach the child process
+pub fn try_solve_part1(input: &str) -> Result<i32, ParseError> {
+    let moves = parse_input(input)?;
+
+    // Follow all the moves, then measure the distance from the origin
+    let position = moves
+        .into_iter()
+        .fold(Hex::ORIGIN, |position, step| position + step);
+    Ok(position.length())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> i32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Solve part 2: Find the furthest distance ever reached from the starting position
+pub fn try_solve_part2(input: &str) -> Result<i32, ParseError> {
+    let moves = parse_input(input)?;
+    let mut position = Hex::ORIGIN;
+    let mut max_distance = 0;
+
+    // Follow all the moves and track the maximum distance
+    for step in moves {
+        position += step;
+        max_distance = max_distance.max(position.length());
+    }
+
+    Ok(max_distance)
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> i32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Hex Ed"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_examples() {
+        // ne,ne,ne is 3 steps away
+        assert_eq!(solve_part1("ne,ne,ne"), 3);
+
+        // ne,ne,sw,sw is 0 steps away (back where you started)
+        assert_eq!(solve_part1("ne,ne,sw,sw"), 0);
+
+        // ne,ne,s,s is 2 steps away (se,se)
+        assert_eq!(solve_part1("ne,ne,s,s"), 2);
+
+        // se,sw,se,sw,sw is 3 steps away (s,s,sw)
+        assert_eq!(solve_part1("se,sw,se,sw,sw"), 3);
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = include_str!("input.txt");
+        assert_eq!(solve_part1(input), 664);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        // The examples should track max distance during the journey
+        // ne,ne,ne - moves 3 steps away, max is 3
+        assert_eq!(solve_part2("ne,ne,ne"), 3);
+
+        // ne,ne,sw,sw - goes out 2 steps then back to origin, max is 2
+        assert_eq!(solve_part2("ne,ne,sw,sw"), 2);
+
+        // ne,ne,s,s - goes out 2 steps then changes direction but stays at 2
+        assert_eq!(solve_part2("ne,ne,s,s"), 2);
+
+        // se,sw,se,sw,sw - varies in distance, need to track the max
+        assert_eq!(solve_part2("se,sw,se,sw,sw"), 3);
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = include_str!("input.txt");
+        assert_eq!(solve_part2(input), 1447);
+    }
+}
@@ -0,0 +1,163 @@
+// Day 12: Digital Plumber
+// https://adventofcode.com/2017/day/12
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::graph::{connected_components, Graph};
+use crate::utils::input::{self, ParseError};
+use std::collections::HashMap;
+
+/// Parse the input to build a graph of program connections
+fn parse_input(input: &str) -> Result<Graph<u32>, ParseError> {
+    let mut graph = HashMap::new();
+
+    for line in input::numbered_lines(input) {
+        // Parse line like "0 <-> 46, 1376"
+        let (id, rest) = line.key_value(" <-> ", "id <-> ids")?;
+        let program_id: u32 = line.parse(id, "program id")?;
+        let connections: Vec<u32> = line.separated(rest, ",", "program id")?;
+        graph.insert(program_id, connections);
+    }
+
+    Ok(graph)
+}
+
+/// Solve part 1: Find how many programs are in the group that contains program ID 0
+/// Approach: Parse the input to build a graph, then split it into connected
+/// components and take the size of program 0's.
+pub fn try_solve_part1(input: &str) -> Result<usize, ParseError> {
+    let graph = parse_input(input)?;
+    Ok(connected_components(&graph).group_of(&0).len())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> usize {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Solve part 2: Count the total number of groups
+/// Approach: Every group is a connected component of the graph.
+pub fn try_solve_part2(input: &str) -> Result<usize, ParseError> {
+    let graph = parse_input(input)?;
+    Ok(connected_components(&graph).len())
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> usize {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// List every group, largest first, as `N programs: ids`
+pub fn render_groups(input: &str) -> Result<String, ParseError> {
+    let graph = parse_input(input)?;
+    let mut groups = connected_components(&graph).groups;
+    groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+
+    let mut out = format!("{} groups\n", groups.len());
+    for group in groups {
+        let ids: Vec<String> = group.iter().map(u32::to_string).collect();
+        out += &format!("{} programs: {}\n", group.len(), ids.join(", "));
+    }
+    Ok(out)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Digital Plumber"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn inspect(&self, input: &str) -> Option<Result<String, ParseError>> {
+        Some(render_groups(input))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input;
+
+    #[test]
+    fn test_part1_examples() {
+        let example = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5";
+
+        assert_eq!(solve_part1(example), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("0 <-> 2\n2 <-> 0, x").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 10, "x"));
+        let err = try_solve_part2("0 -> 2").unwrap_err();
+        assert_eq!(err.message, "expected 'id <-> ids'");
+    }
+
+    #[test]
+    fn test_render_groups() {
+        let example = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5";
+
+        assert_eq!(
+            render_groups(example).unwrap(),
+            "2 groups\n6 programs: 0, 2, 3, 4, 5, 6\n1 programs: 1\n"
+        );
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = input::read_input("src/solutions/y2017/day12/input.txt").unwrap();
+        let result = solve_part1(&input);
+        assert_eq!(result, 152);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        let example = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5";
+
+        assert_eq!(solve_part2(example), 2);
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = input::read_input("src/solutions/y2017/day12/input.txt").unwrap();
+        let result = solve_part2(&input);
+        assert_eq!(result, 186);
+    }
+}
@@ -1,29 +1,21 @@
 // Day 13: Packet Scanners
 // https://adventofcode.com/2017/day/13
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, ParseError};
 use std::collections::HashMap;
 
 /// Parse the input to get the firewall layers
-fn parse_input(input: &str) -> HashMap<usize, usize> {
+fn parse_input(input: &str) -> Result<HashMap<usize, usize>, ParseError> {
     let mut layers = HashMap::new();
 
-    for line in input.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
+    for line in input::numbered_lines(input) {
         // Parse line like "0: 3" (tolerant to whitespace)
-        if let Some((d, r)) = line.split_once(':') {
-            let depth: usize = d.trim().parse().expect("invalid depth");
-            let range: usize = r.trim().parse().expect("invalid range");
-            layers.insert(depth, range);
-        } else {
-            panic!("Invalid input line: {}", line);
-        }
+        let (d, r) = line.key_value(":", "depth: range")?;
+        layers.insert(line.parse(d, "depth")?, line.parse(r, "range")?);
     }
 
-    layers
+    Ok(layers)
 }
 
 /// Calculate if a scanner is at position 0 at a given time
@@ -33,15 +25,15 @@ fn scanner_at_top(range: usize, time: usize) -> bool {
     if range <= 1 {
         return true; // Always at top if range is 1
     }
-    time % (2 * (range - 1)) == 0
+    time.is_multiple_of(2 * (range - 1))
 }
 
 /// Solve part 1: Calculate the severity of the trip if we leave immediately
 /// We enter layer at depth D at time D picoseconds
 /// We get caught if the scanner is at position 0 when we enter
 /// Severity = sum of (depth * range) for layers where we're caught
-pub fn solve_part1(input: &str) -> usize {
-    let layers = parse_input(input);
+pub fn try_solve_part1(input: &str) -> Result<usize, ParseError> {
+    let layers = parse_input(input)?;
     let mut severity = 0;
 
     for (&depth, &range) in &layers {
@@ -51,7 +43,12 @@ pub fn solve_part1(input: &str) -> usize {
         }
     }
 
-    severity
+    Ok(severity)
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> usize {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
 }
 
 /// Check if we get caught with a given delay
@@ -60,7 +57,7 @@ fn is_caught(layers: &HashMap<usize, usize>, delay: usize) -> bool {
     for (&depth, &range) in layers {
         // We reach this layer at time = delay + depth
         let period = if range <= 1 { 1 } else { 2 * (range - 1) };
-        if (delay + depth) % period == 0 {
+        if (delay + depth).is_multiple_of(period) {
             return true;
         }
     }
@@ -69,8 +66,8 @@ fn is_caught(layers: &HashMap<usize, usize>, delay: usize) -> bool {
 
 /// Solve part 2: Find the minimum delay before starting
 /// We need to find the minimum delay such that we don't get caught at any layer
-pub fn solve_part2(input: &str) -> usize {
-    let layers = parse_input(input);
+pub fn try_solve_part2(input: &str) -> Result<usize, ParseError> {
+    let layers = parse_input(input)?;
 
     // Start from delay 0 and increment until we find a safe path
     let mut delay = 0;
@@ -78,7 +75,40 @@ pub fn solve_part2(input: &str) -> usize {
         delay += 1;
     }
 
-    delay
+    Ok(delay)
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> usize {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u32 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Packet Scanners"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -125,10 +155,21 @@ mod tests {
         assert!(scanner_at_top(4, 6));
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("0: 3\n1 2").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "expected 'depth: range'")
+        );
+        let err = try_solve_part2("0: 3\n1: -2").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 4, "-2"));
+    }
+
     #[test]
     fn test_part1_input() {
         let input = include_str!("input.txt");
-        assert_eq!(solve_part1(&input), 1640);
+        assert_eq!(solve_part1(input), 1640);
     }
 
     #[test]
@@ -143,6 +184,6 @@ mod tests {
     #[test]
     fn test_part2_input() {
         let input = include_str!("input.txt");
-        assert_eq!(solve_part2(&input), 3960702);
+        assert_eq!(solve_part2(input), 3960702);
     }
 }
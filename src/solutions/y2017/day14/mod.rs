@@ -0,0 +1,121 @@
+// Day 14: Disk Defragmentation
+// https://adventofcode.com/2017/day/14
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::graph::UnionFind;
+use crate::utils::grid::{Grid, Point};
+use crate::utils::hash::knot_hash;
+use crate::utils::input::ParseError;
+
+/// Convert a hexadecimal string to a binary string
+fn hex_to_binary(hex: &str) -> String {
+    hex.chars()
+        .map(|c| {
+            let digit = c.to_digit(16).unwrap();
+            format!("{:04b}", digit)
+        })
+        .collect()
+}
+
+/// Solve part 1: Count the number of used squares in the 128x128 grid
+/// Approach: Generate 128 knot hashes (one per row), convert each from hex to binary,
+/// and count all the '1' bits across all hashes.
+pub fn solve_part1(input: &str) -> u32 {
+    build_grid(input.trim()).count(|&used| used) as u32
+}
+
+/// Build the 128x128 grid of used/free squares
+fn build_grid(key: &str) -> Grid<bool> {
+    let rows = (0..128)
+        .map(|row| {
+            let hash = knot_hash(&format!("{}-{}", key, row));
+            hex_to_binary(&hash).chars().map(|c| c == '1').collect()
+        })
+        .collect();
+    Grid::from_rows(rows)
+}
+
+/// Solve part 2: Count the number of regions (connected components) in the grid
+/// Approach: Union every used square with its used neighbours, then count the
+/// sets that hold a used square.
+pub fn solve_part2(input: &str) -> u32 {
+    let grid = build_grid(input.trim());
+    let index = |p: Point| p.y as usize * grid.width() + p.x as usize;
+    let mut regions = UnionFind::new(grid.width() * grid.height());
+
+    for p in grid.points().filter(|&p| grid[p]) {
+        for next in grid.neighbors4(p).filter(|&n| grid[n]) {
+            regions.union(index(p), index(next));
+        }
+    }
+
+    let free = grid.count(|&used| !used);
+    (regions.sets() - free) as u32
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u32 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Defragmentation"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input;
+
+    #[test]
+    fn test_hex_to_binary() {
+        assert_eq!(hex_to_binary("0"), "0000");
+        assert_eq!(hex_to_binary("1"), "0001");
+        assert_eq!(hex_to_binary("e"), "1110");
+        assert_eq!(hex_to_binary("f"), "1111");
+        assert_eq!(hex_to_binary("a0c2017"), "1010000011000010000000010111");
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        // The example uses key "flqrgnkx" and expects 8108 used squares
+        assert_eq!(solve_part1("flqrgnkx"), 8108);
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = input::read_input("src/solutions/y2017/day14/input.txt")
+            .expect("Failed to read input file");
+        let answer = solve_part1(&input);
+        assert_eq!(answer, 8140);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        // The example uses key "flqrgnkx" and expects 1242 regions
+        assert_eq!(solve_part2("flqrgnkx"), 1242);
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = input::read_input("src/solutions/y2017/day14/input.txt")
+            .expect("Failed to read input file");
+        let answer = solve_part2(&input);
+        assert_eq!(answer, 1182);
+    }
+}
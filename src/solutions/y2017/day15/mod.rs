@@ -1,31 +1,44 @@
 // Day 15: Dueling Generators
 // https://adventofcode.com/2017/day/15
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::ParseError;
+
 /// Parse input to get starting values for generators A and B
-fn parse_input(input: &str) -> Result<(u64, u64), &'static str> {
+fn parse_input(input: &str) -> Result<(u64, u64), ParseError> {
     let lines: Vec<&str> = input.lines().collect();
 
     if lines.len() < 2 {
-        return Err("Input must contain at least 2 lines");
-    }
-
-    // Parse "Generator A starts with XXX"
-    let a_start = lines[0]
-        .split_whitespace()
-        .last()
-        .ok_or("Invalid format for Generator A line")?
-        .parse::<u64>()
-        .map_err(|_| "Failed to parse Generator A starting value")?;
-
-    // Parse "Generator B starts with XXX"
-    let b_start = lines[1]
-        .split_whitespace()
-        .last()
-        .ok_or("Invalid format for Generator B line")?
-        .parse::<u64>()
-        .map_err(|_| "Failed to parse Generator B starting value")?;
-
-    Ok((a_start, b_start))
+        return Err(ParseError::new(
+            lines.len() + 1,
+            1,
+            "",
+            "input must contain at least 2 lines",
+        ));
+    }
+
+    // Parse "Generator X starts with NNN"
+    let parse_start = |line_no: usize, name: &str| -> Result<u64, ParseError> {
+        let line = lines[line_no - 1];
+        let token = line.split_whitespace().last().ok_or_else(|| {
+            ParseError::new(
+                line_no,
+                1,
+                line,
+                format!("invalid format for Generator {} line", name),
+            )
+        })?;
+        token.parse::<u64>().map_err(|_| {
+            ParseError::at(
+                line_no,
+                line,
+                token,
+                format!("invalid Generator {} starting value", name),
+            )
+        })
+    };
+
+    Ok((parse_start(1, "A")?, parse_start(2, "B")?))
 }
 
 /// Generator that produces values according to the rules
@@ -67,8 +80,8 @@ impl Generator {
 }
 
 /// Solve part 1: Count matches in lowest 16 bits over 40 million pairs
-pub fn solve_part1(input: &str) -> u32 {
-    let (a_start, b_start) = parse_input(input).expect("Failed to parse input");
+pub fn try_solve_part1(input: &str) -> Result<u32, ParseError> {
+    let (a_start, b_start) = parse_input(input)?;
 
     let mut gen_a = Generator::new(a_start, 16807);
     let mut gen_b = Generator::new(b_start, 48271);
@@ -87,15 +100,20 @@ pub fn solve_part1(input: &str) -> u32 {
         }
     }
 
-    matches
+    Ok(matches)
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> u32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
 }
 
 /// Solve part 2: Count matches with picky generators
 /// Generator A only considers values divisible by 4
 /// Generator B only considers values divisible by 8
 /// Only compare 5 million pairs
-pub fn solve_part2(input: &str) -> u32 {
-    let (a_start, b_start) = parse_input(input).expect("Failed to parse input");
+pub fn try_solve_part2(input: &str) -> Result<u32, ParseError> {
+    let (a_start, b_start) = parse_input(input)?;
 
     let mut gen_a = Generator::new(a_start, 16807);
     let mut gen_b = Generator::new(b_start, 48271);
@@ -116,7 +134,40 @@ pub fn solve_part2(input: &str) -> u32 {
         }
     }
 
-    matches
+    Ok(matches)
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> u32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u32 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Dueling Generators"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
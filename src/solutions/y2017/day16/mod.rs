@@ -0,0 +1,233 @@
+// Day 16: Permutation Promenade
+// https://adventofcode.com/2017/day/16
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::ParseError;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+enum DanceMove {
+    Spin(usize),            // sX: move X programs from end to front
+    Exchange(usize, usize), // xA/B: swap programs at positions A and B
+    Partner(char, char),    // pA/B: swap programs named A and B
+}
+
+/// Number of programs dancing, named `a` onwards
+const PROGRAMS: usize = 16;
+
+/// Parse dance moves from input, rejecting any that reach past the line of programs
+fn parse_moves(input: &str) -> Result<Vec<DanceMove>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|move_str| {
+            let error = |message: &str| ParseError::at(1, input, move_str, message);
+            let mut chars = move_str.chars();
+            match chars.next() {
+                Some('s') => {
+                    let size: usize = move_str[1..]
+                        .parse()
+                        .ok()
+                        .filter(|&size| size <= PROGRAMS)
+                        .ok_or_else(|| error("invalid spin size"))?;
+                    Ok(DanceMove::Spin(size))
+                }
+                Some('x') => {
+                    let (a, b) = move_str[1..]
+                        .split_once('/')
+                        .ok_or_else(|| error("expected 'xA/B'"))?;
+                    let position = |p: &str| {
+                        p.parse()
+                            .ok()
+                            .filter(|&p: &usize| p < PROGRAMS)
+                            .ok_or_else(|| error("invalid position"))
+                    };
+                    let (pos_a, pos_b) = (position(a)?, position(b)?);
+                    Ok(DanceMove::Exchange(pos_a, pos_b))
+                }
+                Some('p') => match (chars.next(), chars.next(), chars.next(), chars.next()) {
+                    (Some(prog_a), Some('/'), Some(prog_b), None) => {
+                        let names = 'a'..(b'a' + PROGRAMS as u8) as char;
+                        if !names.contains(&prog_a) || !names.contains(&prog_b) {
+                            return Err(error("unknown program"));
+                        }
+                        Ok(DanceMove::Partner(prog_a, prog_b))
+                    }
+                    _ => Err(error("expected 'pA/B'")),
+                },
+                _ => Err(error("unknown move")),
+            }
+        })
+        .collect()
+}
+
+/// Apply a single dance move to the programs
+fn apply_move(programs: &mut Vec<char>, dance_move: &DanceMove) {
+    match dance_move {
+        DanceMove::Spin(size) => {
+            let len = programs.len();
+            let split_pos = len - size;
+            let mut new_programs = Vec::with_capacity(len);
+            new_programs.extend_from_slice(&programs[split_pos..]);
+            new_programs.extend_from_slice(&programs[..split_pos]);
+            *programs = new_programs;
+        }
+        DanceMove::Exchange(a, b) => {
+            programs.swap(*a, *b);
+        }
+        DanceMove::Partner(a, b) => {
+            let pos_a = programs.iter().position(|&c| c == *a).unwrap();
+            let pos_b = programs.iter().position(|&c| c == *b).unwrap();
+            programs.swap(pos_a, pos_b);
+        }
+    }
+}
+
+/// Perform one complete dance
+fn dance(programs: &mut Vec<char>, moves: &[DanceMove]) {
+    for dance_move in moves {
+        apply_move(programs, dance_move);
+    }
+}
+
+/// Solve part 1: Find the order of programs after one dance
+pub fn try_solve_part1(input: &str) -> Result<String, ParseError> {
+    let moves = parse_moves(input)?;
+    let mut programs: Vec<char> = ('a'..='p').collect();
+
+    dance(&mut programs, &moves);
+
+    Ok(programs.iter().collect())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> String {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Solve part 2: Find the order after one billion dances
+pub fn try_solve_part2(input: &str) -> Result<String, ParseError> {
+    let moves = parse_moves(input)?;
+    let mut programs: Vec<char> = ('a'..='p').collect();
+
+    // Detect cycle
+    let mut seen: HashMap<Vec<char>, usize> = HashMap::new();
+    let target = 1_000_000_000;
+
+    for i in 0..target {
+        if let Some(&cycle_start) = seen.get(&programs) {
+            // Found a cycle!
+            let cycle_length = i - cycle_start;
+            let remaining = (target - i) % cycle_length;
+
+            // Fast forward to the final position
+            for _ in 0..remaining {
+                dance(&mut programs, &moves);
+            }
+            return Ok(programs.iter().collect());
+        }
+
+        seen.insert(programs.clone(), i);
+        dance(&mut programs, &moves);
+    }
+
+    Ok(programs.iter().collect())
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> String {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u32 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Permutation Promenade"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_moves(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_examples() {
+        // Test with the smaller example from the puzzle
+        let moves = vec![
+            DanceMove::Spin(1),
+            DanceMove::Exchange(3, 4),
+            DanceMove::Partner('e', 'b'),
+        ];
+
+        let mut programs: Vec<char> = vec!['a', 'b', 'c', 'd', 'e'];
+
+        // Apply each move
+        for dance_move in &moves {
+            apply_move(&mut programs, dance_move);
+        }
+
+        assert_eq!(programs.iter().collect::<String>(), "baedc");
+    }
+
+    #[test]
+    fn test_parsing() {
+        let input = "s1,x3/4,pe/b";
+        let moves = parse_moves(input).unwrap();
+
+        assert_eq!(moves.len(), 3);
+        matches!(moves[0], DanceMove::Spin(1));
+        matches!(moves[1], DanceMove::Exchange(3, 4));
+        matches!(moves[2], DanceMove::Partner('e', 'b'));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("s1,x3/4,q3/4").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 9, token 'q3/4': unknown move"
+        );
+        assert!(parse_moves("pe/").is_err());
+        assert!(parse_moves("x3").is_err());
+
+        // Moves naming programs or positions that don't exist
+        for input in ["s17", "x3/16", "pa/q"] {
+            assert!(try_solve_part1(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day16/input.txt").unwrap();
+        let result = solve_part1(&input);
+        assert_eq!(result, "cgpfhdnambekjiol");
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day16/input.txt").unwrap();
+        let result = solve_part2(&input);
+        assert_eq!(result, "gjmiofcnaehpdlbk");
+    }
+}
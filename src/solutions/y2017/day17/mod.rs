@@ -0,0 +1,118 @@
+// Day 17: Spinlock
+// https://adventofcode.com/2017/day/17
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::ParseError;
+use std::collections::VecDeque;
+
+/// Parse the number of steps the spinlock takes before each insertion
+fn parse_steps(input: &str) -> Result<usize, ParseError> {
+    let token = input.trim();
+    token
+        .parse()
+        .map_err(|_| ParseError::at(1, input, token, "invalid step count"))
+}
+
+pub fn try_solve_part1(input: &str) -> Result<String, ParseError> {
+    let steps = parse_steps(input)?;
+
+    // Use VecDeque for efficient insertion
+    let mut buffer = VecDeque::new();
+    buffer.push_back(0);
+    let mut position = 0;
+
+    for value in 1..=2017 {
+        // Step forward
+        position = (position + steps) % buffer.len() + 1;
+
+        // Insert value at position
+        buffer.insert(position, value);
+    }
+
+    // Find the value after 2017
+    let index_2017 = buffer.iter().position(|&x| x == 2017).unwrap();
+    let next_index = (index_2017 + 1) % buffer.len();
+
+    Ok(buffer[next_index].to_string())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> String {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_solve_part2(input: &str) -> Result<String, ParseError> {
+    let steps = parse_steps(input)?;
+
+    // We don't need to build the full buffer.
+    // 0 is always at position 0, so we just track what's at position 1
+    let mut position = 0;
+    let mut value_after_zero = 0;
+
+    for value in 1..=50_000_000 {
+        // Calculate where we'd be after stepping
+        position = (position + steps) % value + 1;
+
+        // If we insert at position 1, that's after 0
+        if position == 1 {
+            value_after_zero = value;
+        }
+    }
+
+    Ok(value_after_zero.to_string())
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> String {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Spinlock"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_steps(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1("3"), "638");
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day17/input.txt").unwrap();
+        assert_eq!(solve_part1(&input), "417");
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day17/input.txt").unwrap();
+        assert_eq!(solve_part2(&input), "34334221");
+    }
+}
@@ -1,6 +1,8 @@
 // Day 18: Duet
 // https://adventofcode.com/2017/day/18
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, Line, ParseError};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -10,11 +12,11 @@ enum Value {
 }
 
 impl Value {
-    fn parse(s: &str) -> Value {
+    fn parse(line: &Line, s: &str) -> Result<Value, ParseError> {
         if let Ok(n) = s.parse::<i64>() {
-            Value::Number(n)
+            Ok(Value::Number(n))
         } else {
-            Value::Register(s.chars().next().unwrap())
+            parse_register(line, s).map(Value::Register)
         }
     }
 
@@ -37,28 +39,43 @@ enum Instruction {
     Jgz(Value, Value),
 }
 
-fn parse_instructions(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .map(|line| {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            match parts[0] {
-                "snd" => Instruction::Snd(Value::parse(parts[1])),
-                "set" => Instruction::Set(parts[1].chars().next().unwrap(), Value::parse(parts[2])),
-                "add" => Instruction::Add(parts[1].chars().next().unwrap(), Value::parse(parts[2])),
-                "mul" => Instruction::Mul(parts[1].chars().next().unwrap(), Value::parse(parts[2])),
-                "mod" => Instruction::Mod(parts[1].chars().next().unwrap(), Value::parse(parts[2])),
-                "rcv" => Instruction::Rcv(parts[1].chars().next().unwrap()),
-                "jgz" => Instruction::Jgz(Value::parse(parts[1]), Value::parse(parts[2])),
-                _ => panic!("Unknown instruction: {}", parts[0]),
-            }
+/// Parse a single-letter register name
+fn parse_register(line: &Line, s: &str) -> Result<char, ParseError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => Ok(c),
+        _ => Err(line.error(s, "invalid register")),
+    }
+}
+
+/// Parse one instruction per non-empty line
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input::parse_each_line(input, |line| {
+        let parts: Vec<&str> = line.text.split_whitespace().collect();
+        let operand = |n: usize| {
+            parts
+                .get(n)
+                .copied()
+                .ok_or_else(|| line.error(parts[0], "missing operand"))
+        };
+        let register = |n: usize| operand(n).and_then(|s| parse_register(&line, s));
+        let value = |n: usize| operand(n).and_then(|s| Value::parse(&line, s));
+
+        Ok(match parts[0] {
+            "snd" => Instruction::Snd(value(1)?),
+            "set" => Instruction::Set(register(1)?, value(2)?),
+            "add" => Instruction::Add(register(1)?, value(2)?),
+            "mul" => Instruction::Mul(register(1)?, value(2)?),
+            "mod" => Instruction::Mod(register(1)?, value(2)?),
+            "rcv" => Instruction::Rcv(register(1)?),
+            "jgz" => Instruction::Jgz(value(1)?, value(2)?),
+            other => return Err(line.error(other, "unknown instruction")),
         })
-        .collect()
+    })
 }
 
-pub fn solve_part1(input: &str) -> String {
-    let instructions = parse_instructions(input);
+pub fn try_solve_part1(input: &str) -> Result<String, ParseError> {
+    let instructions = parse_instructions(input)?;
     let mut registers: HashMap<char, i64> = HashMap::new();
     let mut pc = 0i64;
     let mut last_sound = 0i64;
@@ -89,7 +106,7 @@ pub fn solve_part1(input: &str) -> String {
             }
             Instruction::Rcv(reg) => {
                 if *registers.get(reg).unwrap_or(&0) != 0 {
-                    return last_sound.to_string();
+                    return Ok(last_sound.to_string());
                 }
             }
             Instruction::Jgz(check, offset) => {
@@ -101,11 +118,16 @@ pub fn solve_part1(input: &str) -> String {
         pc += 1;
     }
 
-    "0".to_string()
+    Ok("0".to_string())
 }
 
-pub fn solve_part2(input: &str) -> String {
-    let instructions = parse_instructions(input);
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> String {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_solve_part2(input: &str) -> Result<String, ParseError> {
+    let instructions = parse_instructions(input)?;
 
     // Program state
     struct Program {
@@ -117,7 +139,7 @@ pub fn solve_part2(input: &str) -> String {
         waiting: bool,
     }
 
-    let mut programs = vec![
+    let mut programs = [
         Program {
             _id: 0,
             registers: HashMap::from([('p', 0)]),
@@ -221,7 +243,40 @@ pub fn solve_part2(input: &str) -> String {
         }
     }
 
-    programs[1].send_count.to_string()
+    Ok(programs[1].send_count.to_string())
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> String {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> u32 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "Duet"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_instructions(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -243,15 +298,28 @@ jgz a -2";
         assert_eq!(solve_part1(input), "4");
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("set a 1\nsnd a\nfoo a 2").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.message, "unknown instruction");
+
+        let err = try_solve_part2("set a 1\nadd 12 b").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.token, "12");
+
+        assert!(try_solve_part1("set a").is_err());
+    }
+
     #[test]
     fn test_part1_input() {
-        let input = std::fs::read_to_string("src/solutions/day18/input.txt").unwrap();
+        let input = std::fs::read_to_string("src/solutions/y2017/day18/input.txt").unwrap();
         assert_eq!(solve_part1(&input), "9423");
     }
 
     #[test]
     fn test_part2_input() {
-        let input = std::fs::read_to_string("src/solutions/day18/input.txt").unwrap();
+        let input = std::fs::read_to_string("src/solutions/y2017/day18/input.txt").unwrap();
         assert_eq!(solve_part2(&input), "7620");
     }
 }
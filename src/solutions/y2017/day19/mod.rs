@@ -0,0 +1,122 @@
+// Day 19: A Series of Tubes
+// https://adventofcode.com/2017/day/19
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::grid::{Direction, Grid, Point};
+use crate::utils::input::{self, ParseError};
+
+fn find_path(grid: &Grid<char>) -> (String, usize) {
+    // Find starting position (only | in first row)
+    let start = (0..grid.width() as i32)
+        .map(|x| Point::new(x, 0))
+        .find(|&p| grid[p] == '|')
+        .unwrap_or(Point::ORIGIN);
+
+    let mut position = start;
+    let mut direction = Direction::Down;
+    let mut letters = String::new();
+    let mut steps = 0;
+
+    loop {
+        // Move in current direction
+        position += direction;
+        steps += 1;
+
+        // Off the grid or onto an empty cell ends the path
+        match grid.get(position).copied().unwrap_or(' ') {
+            ' ' => break,
+            '+' => {
+                // Turn towards whichever perpendicular neighbour is not empty
+                let open = |d: Direction| grid.get(position + d).is_some_and(|&c| c != ' ');
+                if open(direction.turn_left()) {
+                    direction = direction.turn_left();
+                } else if open(direction.turn_right()) {
+                    direction = direction.turn_right();
+                }
+            }
+            c if c.is_ascii_alphabetic() => letters.push(c),
+            _ => {} // Continue straight
+        }
+    }
+
+    (letters, steps)
+}
+
+fn parse_grid(input: &str) -> Grid<char> {
+    Grid::from_rows_padded(input::char_grid(input), ' ')
+}
+
+pub fn solve_part1(input: &str) -> String {
+    let (letters, _) = find_path(&parse_grid(input));
+    letters
+}
+
+pub fn solve_part2(input: &str) -> String {
+    let (_, steps) = find_path(&parse_grid(input));
+    steps.to_string()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u32 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "A Series of Tubes"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_example() {
+        let input = "     |          
+     |  +--+    
+     A  |  C    
+ F---|----E|--+ 
+     |  |  |  D 
+     +B-+  +--+ 
+";
+        assert_eq!(solve_part1(input), "ABCDEF");
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = "     |          
+     |  +--+    
+     A  |  C    
+ F---|----E|--+ 
+     |  |  |  D 
+     +B-+  +--+ 
+";
+        assert_eq!(solve_part2(input), "38");
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day19/input.txt").unwrap();
+        assert_eq!(solve_part1(&input), "DWNBGECOMY");
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day19/input.txt").unwrap();
+        assert_eq!(solve_part2(&input), "17228");
+    }
+}
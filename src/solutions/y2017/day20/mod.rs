@@ -0,0 +1,163 @@
+// Day 20: Particle Swarm
+// https://adventofcode.com/2017/day/20
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::geom::Vec3;
+use crate::utils::input::{self, Line, ParseError};
+use std::collections::HashMap;
+
+type Vector3 = Vec3<i64>;
+
+#[derive(Debug, Clone)]
+struct Particle {
+    position: Vector3,
+    velocity: Vector3,
+    acceleration: Vector3,
+}
+
+impl Particle {
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        match line.ints()?[..] {
+            [px, py, pz, vx, vy, vz, ax, ay, az] => Ok(Particle {
+                position: Vector3::new(px, py, pz),
+                velocity: Vector3::new(vx, vy, vz),
+                acceleration: Vector3::new(ax, ay, az),
+            }),
+            _ => {
+                // Point at a stray token such as `x` in `v=<0,x,0>` if there is one
+                let stray = line.text.split(|c: char| ",<>= ".contains(c)).find(|t| {
+                    !t.is_empty() && !["p", "v", "a"].contains(t) && t.parse::<i64>().is_err()
+                });
+                Err(match stray {
+                    Some(token) => line.error(token, "invalid coordinate"),
+                    None => line.error(line.text, "expected position, velocity and acceleration"),
+                })
+            }
+        }
+    }
+
+    fn tick(&mut self) {
+        self.velocity += self.acceleration;
+        self.position += self.velocity;
+    }
+}
+
+/// Parse one particle per non-empty line
+fn parse_particles(input: &str) -> Result<Vec<Particle>, ParseError> {
+    input::parse_each_line(input, Particle::parse)
+}
+
+pub fn try_solve_part1(input: &str) -> Result<String, ParseError> {
+    let particles = parse_particles(input)?;
+
+    // The particle that stays closest in the long term is the one with the smallest acceleration
+    // If tied, then smallest velocity, then smallest initial position
+    Ok(particles
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, p)| {
+            (
+                p.acceleration.manhattan(Vector3::zero()),
+                p.velocity.manhattan(Vector3::zero()),
+                p.position.manhattan(Vector3::zero()),
+            )
+        })
+        .map(|(i, _)| i.to_string())
+        .unwrap_or_default())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> String {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_solve_part2(input: &str) -> Result<String, ParseError> {
+    let mut particles = parse_particles(input)?;
+
+    let mut alive: Vec<bool> = vec![true; particles.len()];
+
+    // Simulate for enough ticks to let collisions happen
+    for _ in 0..1000 {
+        // Update positions
+        for (i, particle) in particles.iter_mut().enumerate() {
+            if alive[i] {
+                particle.tick();
+            }
+        }
+
+        // Check for collisions
+        let mut position_map: HashMap<Vector3, Vec<usize>> = HashMap::new();
+        for (i, particle) in particles.iter().enumerate() {
+            if alive[i] {
+                position_map.entry(particle.position).or_default().push(i);
+            }
+        }
+
+        // Remove collided particles
+        for indices in position_map.values() {
+            if indices.len() > 1 {
+                for &i in indices {
+                    alive[i] = false;
+                }
+            }
+        }
+    }
+
+    Ok(alive.iter().filter(|&&a| a).count().to_string())
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> String {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn day(&self) -> u32 {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Particle Swarm"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_particles(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_example() {
+        let input = "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
+p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>";
+        assert_eq!(solve_part1(input), "0");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
+p=<4,0,0>, v=<0,x,0>, a=<-2,0,0>";
+        let err = try_solve_part1(input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 17));
+        assert_eq!(err.token, "x");
+
+        assert!(try_solve_part2("p=<4,0,0>, v=<0,0>, a=<-2,0,0>").is_err());
+    }
+}
@@ -0,0 +1,202 @@
+// Day 21: Fractal Art
+// https://adventofcode.com/2017/day/21
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::grid::{Grid, Point};
+use crate::utils::input::{self, Line, ParseError};
+use std::collections::HashMap;
+
+type Rules = HashMap<Grid<bool>, Grid<bool>>;
+
+/// Parse a pattern such as `.#./..#/###`, with `#` for pixels that are on
+fn parse_pattern(line: &Line, s: &str) -> Result<Grid<bool>, ParseError> {
+    let rows = s
+        .split('/')
+        .map(|row| {
+            row.char_indices()
+                .map(|(i, c)| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(line.error(&row[i..i + c.len_utf8()], "expected '#' or '.'")),
+                })
+                .collect::<Result<Vec<bool>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if rows.iter().any(|row| row.len() != rows.len()) {
+        return Err(line.error(s, "expected a square pattern"));
+    }
+    Ok(Grid::from_rows(rows))
+}
+
+/// A pattern in the rulebook's notation, e.g. `.#./..#/###`
+fn format_pattern(grid: &Grid<bool>) -> String {
+    let rows: Vec<String> = grid
+        .rows()
+        .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+        .collect();
+    rows.join("/")
+}
+
+/// Enhance every block of `grid`; a block without a rule is reported against
+/// `first_line`, where the rulebook starts
+fn enhance(grid: &Grid<bool>, rules: &Rules, first_line: usize) -> Result<Grid<bool>, ParseError> {
+    let size = grid.width();
+    let block_size = if size.is_multiple_of(2) { 2 } else { 3 };
+    let blocks_per_side = size / block_size;
+    let new_block_size = block_size + 1;
+    let new_size = blocks_per_side * new_block_size;
+
+    let mut result = Grid::new(new_size, new_size, false);
+
+    for block_row in 0..blocks_per_side {
+        for block_col in 0..blocks_per_side {
+            let origin = Point::new(
+                (block_col * block_size) as i32,
+                (block_row * block_size) as i32,
+            );
+            let block = grid.block(origin, block_size, block_size);
+            let enhanced = rules.get(&block).ok_or_else(|| {
+                let pattern = format_pattern(&block);
+                ParseError::new(first_line, 1, &pattern, "no rule matches this pattern")
+            })?;
+
+            let target = Point::new(
+                (block_col * new_block_size) as i32,
+                (block_row * new_block_size) as i32,
+            );
+            result.paste(target, enhanced);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Parse `from => to` rules, keyed by every rotation and flip of `from`
+fn parse_rules(input: &str) -> Result<Rules, ParseError> {
+    let mut rules = HashMap::new();
+    for line in input::numbered_lines(input) {
+        let (from_text, to_text) = line.key_value(" => ", "pattern => pattern")?;
+        let from = parse_pattern(&line, from_text)?;
+        let to = parse_pattern(&line, to_text)?;
+
+        // Only 2x2 and 3x3 squares are ever split off, and each grows by one
+        if !(2..=3).contains(&from.width()) {
+            return Err(line.error(from_text, "expected a 2x2 or 3x3 pattern"));
+        }
+        let size = from.width() + 1;
+        if to.width() != size {
+            return Err(line.error(to_text, format!("expected a {}x{} pattern", size, size)));
+        }
+
+        // Store all transformations
+        for transform in from.orientations() {
+            rules.insert(transform, to.clone());
+        }
+    }
+    Ok(rules)
+}
+
+/// Pixels on after enhancing the starting pattern `iterations` times
+fn count_after(input: &str, iterations: usize) -> Result<usize, ParseError> {
+    let rules = parse_rules(input)?;
+    let mut grid = Grid::parse_with(".#.\n..#\n###", |c| c == '#')?;
+    let first_line = input::numbered_lines(input)
+        .next()
+        .map_or(1, |line| line.number);
+
+    for _ in 0..iterations {
+        grid = enhance(&grid, &rules, first_line)?;
+    }
+
+    Ok(grid.count(|&on| on))
+}
+
+pub fn try_solve_part1(input: &str) -> Result<String, ParseError> {
+    count_after(input, 5).map(|count| count.to_string())
+}
+
+pub fn try_solve_part2(input: &str) -> Result<String, ParseError> {
+    count_after(input, 18).map(|count| count.to_string())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> String {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> String {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> u32 {
+        21
+    }
+
+    fn title(&self) -> &'static str {
+        "Fractal Art"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_rules(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_example() {
+        let input = "../.# => ##./#../...
+.#./..#/### => #..#/..../..../#..#";
+        // After 2 iterations, should have 12 pixels on
+        assert_eq!(count_after(input, 2).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err =
+            try_solve_part1("../.# => ##./#../...\n.#./..#/### #..#/..../..../#..#").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "expected 'pattern => pattern'")
+        );
+
+        // Outputs must be one pixel wider than their inputs
+        let err =
+            try_solve_part1("../.# => ##./#../...\n../.. => ####/####/####/####").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 10, "expected a 3x3 pattern")
+        );
+        let err = try_solve_part1("../.. => #").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (1, "#"));
+        let err = try_solve_part1("# => ../..").unwrap_err();
+        assert_eq!(err.message, "expected a 2x2 or 3x3 pattern");
+        let err = try_solve_part1("../.x => ##./#../...").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (5, "x"));
+
+        // The starting pattern is 3x3, but only a 2x2 rule is given
+        let err = try_solve_part2("\n../.# => ##./#../...\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.token.as_str(), err.message.as_str()),
+            (2, ".#./..#/###", "no rule matches this pattern")
+        );
+    }
+}
@@ -0,0 +1,251 @@
+// Day 22: Sporifica Virus
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::grid::{Direction, Grid, Point, SparseGrid};
+use crate::utils::input::{self, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NodeState {
+    Clean,
+    Infected,
+    Weakened,
+    Flagged,
+}
+
+/// Parse the starting grid, where `#` is infected and `.` is clean
+fn parse_input(input: &str) -> Result<Grid<NodeState>, ParseError> {
+    for line in input::numbered_lines(input) {
+        if let Some((i, c)) = line
+            .text
+            .char_indices()
+            .find(|&(_, c)| c != '#' && c != '.')
+        {
+            return Err(line.error(&line.text[i..i + c.len_utf8()], "expected '#' or '.'"));
+        }
+    }
+    Grid::parse_with(input, |ch| match ch {
+        '#' => NodeState::Infected,
+        _ => NodeState::Clean,
+    })
+}
+
+/// Top-left corner that puts the middle of the starting grid at (0, 0)
+fn origin(grid: &Grid<NodeState>) -> Point {
+    Point::new(-(grid.width() as i32 / 2), -(grid.height() as i32 / 2))
+}
+
+/// The infinite cluster, with every node outside the starting grid clean
+fn parse_cluster(input: &str) -> Result<SparseGrid<NodeState>, ParseError> {
+    let grid = parse_input(input)?;
+    Ok(SparseGrid::from_grid(
+        &grid,
+        origin(&grid),
+        NodeState::Clean,
+    ))
+}
+
+pub fn try_solve_part1(input: &str) -> Result<i32, ParseError> {
+    let mut grid = parse_cluster(input)?;
+    let mut position = Point::ORIGIN;
+    let mut direction = Direction::Up;
+    let mut infection_count = 0;
+
+    for _ in 0..10000 {
+        // Get current node state (default to Clean if not in grid)
+        let current_state = *grid.get(position);
+
+        // Turn based on current node state
+        direction = match current_state {
+            NodeState::Infected => direction.turn_right(),
+            NodeState::Clean => direction.turn_left(),
+            _ => direction, // For part 1, we only have Clean and Infected
+        };
+
+        // Toggle the current node state
+        match current_state {
+            NodeState::Clean => {
+                grid.set(position, NodeState::Infected);
+                infection_count += 1;
+            }
+            NodeState::Infected => {
+                grid.set(position, NodeState::Clean); // Clean nodes are not stored
+            }
+            _ => {} // For part 1, we only have Clean and Infected
+        }
+
+        // Move forward
+        position += direction;
+    }
+
+    Ok(infection_count)
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> i32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_solve_part2(input: &str) -> Result<i32, ParseError> {
+    let mut grid = parse_cluster(input)?;
+    let mut position = Point::ORIGIN;
+    let mut direction = Direction::Up;
+    let mut infection_count = 0;
+
+    for _ in 0..10000000 {
+        // Get current node state (default to Clean if not in grid)
+        let current_state = *grid.get(position);
+
+        // Turn based on current node state
+        direction = match current_state {
+            NodeState::Clean => direction.turn_left(),
+            NodeState::Weakened => direction, // No turn
+            NodeState::Infected => direction.turn_right(),
+            NodeState::Flagged => direction.reverse(),
+        };
+
+        // Update the current node state
+        match current_state {
+            NodeState::Clean => {
+                grid.set(position, NodeState::Weakened);
+            }
+            NodeState::Weakened => {
+                grid.set(position, NodeState::Infected);
+                infection_count += 1;
+            }
+            NodeState::Infected => {
+                grid.set(position, NodeState::Flagged);
+            }
+            NodeState::Flagged => {
+                grid.set(position, NodeState::Clean);
+            }
+        }
+
+        // Move forward
+        position += direction;
+    }
+
+    Ok(infection_count)
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> i32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Draw the starting grid like the puzzle does, with the carrier's node in brackets
+pub fn render_grid(input: &str) -> Result<String, ParseError> {
+    let grid = parse_input(input)?;
+    let min = origin(&grid);
+    let max = min + Point::new(grid.width() as i32 - 1, grid.height() as i32 - 1);
+    let cluster = SparseGrid::from_grid(&grid, min, NodeState::Clean);
+
+    let rows = cluster.render(min, max, |p, state| {
+        let ch = match state {
+            NodeState::Infected => '#',
+            _ => '.',
+        };
+        if p == Point::ORIGIN {
+            format!("[{}]", ch)
+        } else {
+            format!(" {} ", ch)
+        }
+    });
+    let mut out = format!("{} infected nodes\n", cluster.len());
+    for row in rows.lines() {
+        out += row.trim_end();
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> u32 {
+        22
+    }
+
+    fn title(&self) -> &'static str {
+        "Sporifica Virus"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn inspect(&self, input: &str) -> Option<Result<String, ParseError>> {
+        Some(render_grid(input))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_examples() {
+        let input = "..#\n#..\n...";
+
+        // According to the puzzle, the example should give 5587 infections after 10000 bursts
+        assert_eq!(solve_part1(input), 5587);
+    }
+
+    #[test]
+    fn test_render_grid() {
+        let input = "..#\n#..\n...";
+        assert_eq!(
+            render_grid(input).unwrap(),
+            "2 infected nodes\n .  .  #\n # [.] .\n .  .  .\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = try_solve_part1("..#\n#.\n...").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "expected 3 columns, found 2")
+        );
+
+        let err = try_solve_part2("..#\n#o.\n...").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(
+            (err.token.as_str(), err.message.as_str()),
+            ("o", "expected '#' or '.'")
+        );
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day22/input.txt").unwrap();
+        let result = solve_part1(&input);
+        assert_eq!(result, 5447);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        let input = "..#\n#..\n...";
+
+        // According to the puzzle, the example should give 2511944 infections after 10000000 bursts
+        assert_eq!(solve_part2(input), 2511944);
+    }
+
+    #[test]
+    fn test_part2_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day22/input.txt").unwrap();
+        let result = solve_part2(&input);
+        assert_eq!(result, 2511705);
+    }
+}
@@ -1,5 +1,7 @@
 // Day 24: Electromagnetic Moat
 
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, Line, ParseError};
 use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -9,12 +11,12 @@ struct Component {
 }
 
 impl Component {
-    fn parse(line: &str) -> Component {
-        let parts: Vec<&str> = line.split('/').collect();
-        Component {
-            port1: parts[0].parse().unwrap(),
-            port2: parts[1].parse().unwrap(),
-        }
+    fn parse(line: Line) -> Result<Component, ParseError> {
+        let (a, b) = line.key_value("/", "A/B")?;
+        Ok(Component {
+            port1: line.parse(a, "port")?,
+            port2: line.parse(b, "port")?,
+        })
     }
 
     fn strength(&self) -> i32 {
@@ -97,27 +99,62 @@ fn find_longest_bridge(
     best
 }
 
-pub fn solve_part1(input: &str) -> i32 {
-    let components: Vec<Component> = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(Component::parse)
-        .collect();
+/// Parse one component per non-empty line
+fn parse_components(input: &str) -> Result<Vec<Component>, ParseError> {
+    input::parse_each_line(input, Component::parse)
+}
+
+pub fn try_solve_part1(input: &str) -> Result<i32, ParseError> {
+    let components = parse_components(input)?;
 
     let mut used = HashSet::new();
-    find_strongest_bridge(&components, &mut used, 0, 0)
+    Ok(find_strongest_bridge(&components, &mut used, 0, 0))
 }
 
-pub fn solve_part2(input: &str) -> i32 {
-    let components: Vec<Component> = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(Component::parse)
-        .collect();
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> i32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_solve_part2(input: &str) -> Result<i32, ParseError> {
+    let components = parse_components(input)?;
 
     let mut used = HashSet::new();
     let (_, strength) = find_longest_bridge(&components, &mut used, 0, 0, 0);
-    strength
+    Ok(strength)
+}
+
+/// Solve part 2, panicking on malformed input
+pub fn solve_part2(input: &str) -> i32 {
+    try_solve_part2(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn day(&self) -> u32 {
+        24
+    }
+
+    fn title(&self) -> &'static str {
+        "Electromagnetic Moat"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_components(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -140,7 +177,7 @@ mod tests {
 
     #[test]
     fn test_part1_input() {
-        let input = std::fs::read_to_string("src/solutions/day24/input.txt").unwrap();
+        let input = std::fs::read_to_string("src/solutions/y2017/day24/input.txt").unwrap();
         let result = solve_part1(&input);
         assert_eq!(result, 1859);
     }
@@ -162,7 +199,7 @@ mod tests {
 
     #[test]
     fn test_part2_input() {
-        let input = std::fs::read_to_string("src/solutions/day24/input.txt").unwrap();
+        let input = std::fs::read_to_string("src/solutions/y2017/day24/input.txt").unwrap();
         let result = solve_part2(&input);
         assert_eq!(result, 1799);
     }
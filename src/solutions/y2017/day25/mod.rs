@@ -0,0 +1,304 @@
+// Day 25: The Halting Problem
+// https://adventofcode.com/2017/day/25
+//
+// Part 1: Simulate a Turing machine following the given blueprint and count 1s on tape
+// Part 2: No Part 2 for Day 25 - this is the final day of Advent of Code
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::{self, Line, ParseError};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+struct StateRule {
+    write_value: i32,
+    direction: Direction,
+    next_state: char,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    rules: HashMap<i32, StateRule>,
+}
+
+struct TuringMachine {
+    tape: HashMap<i32, i32>,
+    cursor: i32,
+    current_state: char,
+    states: HashMap<char, State>,
+    steps_to_run: usize,
+}
+
+/// Line `i` of a block, failing if the block ends before it
+fn line_at<'a>(block: &[Line<'a>], i: usize) -> Result<Line<'a>, ParseError> {
+    block.get(i).copied().ok_or_else(|| {
+        // Count on from the block's last line to where line `i` would be
+        let number = match block.last() {
+            Some(last) => last.number + i + 1 - block.len(),
+            None => i + 1,
+        };
+        ParseError::new(number, 1, "", "unexpected end of input")
+    })
+}
+
+/// Fail on any line past the first `len` of a block
+fn expect_len(block: &[Line], len: usize) -> Result<(), ParseError> {
+    match block.get(len) {
+        Some(extra) => Err(extra.error(extra.text.trim(), "unexpected line")),
+        None => Ok(()),
+    }
+}
+
+/// Extract a single-character state name
+fn state_name(line: Line, prefix: &str, suffix: &str) -> Result<char, ParseError> {
+    let token = line.field(prefix, suffix)?;
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(line.error(token, "invalid state name")),
+    }
+}
+
+/// Parse the three instruction lines of a rule starting at line `i` of a block
+fn parse_rule(block: &[Line], i: usize) -> Result<StateRule, ParseError> {
+    let line = line_at(block, i)?;
+    let write_value = match line.field("- Write the value ", ".")? {
+        "0" => 0,
+        "1" => 1,
+        other => return Err(line.error(other, "invalid value")),
+    };
+    let line = line_at(block, i + 1)?;
+    let direction = match line.field("- Move one slot to the ", ".")? {
+        "left" => Direction::Left,
+        "right" => Direction::Right,
+        other => return Err(line.error(other, "invalid direction")),
+    };
+    let next_state = state_name(line_at(block, i + 2)?, "- Continue with state ", ".")?;
+
+    Ok(StateRule {
+        write_value,
+        direction,
+        next_state,
+    })
+}
+
+/// Parse a state's block: its name, then one rule for value 0 and one for value 1
+fn parse_state(block: &[Line]) -> Result<(char, State), ParseError> {
+    let state_char = state_name(line_at(block, 0)?, "In state ", ":")?;
+
+    let mut rules = HashMap::new();
+    for value in 0..=1 {
+        let i = 1 + 4 * value as usize;
+        let expected = format!("If the current value is {}:", value);
+        let header = line_at(block, i)?;
+        if header.text.trim() != expected {
+            return Err(header.error(header.text.trim(), format!("expected '{}'", expected)));
+        }
+        rules.insert(value, parse_rule(block, i + 1)?);
+    }
+    expect_len(block, 9)?;
+
+    Ok((state_char, State { rules }))
+}
+
+impl TuringMachine {
+    fn parse(input: &str) -> Result<TuringMachine, ParseError> {
+        let blocks = input::blocks(input);
+        let mut blocks = blocks.iter().map(Vec::as_slice);
+
+        // The first block gives the initial state and step count
+        let header = blocks.next().unwrap_or_default();
+        let initial_state = state_name(line_at(header, 0)?, "Begin in state ", ".")?;
+        let steps_line = line_at(header, 1)?;
+        let steps_to_run = steps_line.parse(
+            steps_line.field("Perform a diagnostic checksum after ", " steps.")?,
+            "step count",
+        )?;
+        expect_len(header, 2)?;
+
+        // Every other block describes one state
+        let state_blocks: Vec<&[Line]> = blocks.collect();
+        let states: HashMap<char, State> = state_blocks
+            .iter()
+            .map(|block| parse_state(block))
+            .collect::<Result<_, _>>()?;
+
+        // The machine must never be sent to a state without a block
+        let mentions =
+            std::iter::once((header[0], "Begin in state ")).chain(state_blocks.iter().flat_map(
+                |block| [block[4], block[8]].map(|line| (line, "- Continue with state ")),
+            ));
+        for (line, prefix) in mentions {
+            let token = line.field(prefix, ".")?;
+            if !states.contains_key(&state_name(line, prefix, ".")?) {
+                return Err(line.error(token, "undefined state"));
+            }
+        }
+
+        Ok(TuringMachine {
+            tape: HashMap::new(),
+            cursor: 0,
+            current_state: initial_state,
+            states,
+            steps_to_run,
+        })
+    }
+
+    fn run(&mut self) -> i32 {
+        for _ in 0..self.steps_to_run {
+            let current_value = *self.tape.get(&self.cursor).unwrap_or(&0);
+            let state = &self.states[&self.current_state];
+            let rule = &state.rules[&current_value];
+
+            // Write value
+            if rule.write_value == 0 {
+                self.tape.remove(&self.cursor);
+            } else {
+                self.tape.insert(self.cursor, rule.write_value);
+            }
+
+            // Move cursor
+            match rule.direction {
+                Direction::Left => self.cursor -= 1,
+                Direction::Right => self.cursor += 1,
+            }
+
+            // Change state
+            self.current_state = rule.next_state;
+        }
+
+        // Count 1s on the tape
+        self.tape.values().filter(|&&v| v == 1).count() as i32
+    }
+}
+
+pub fn try_solve_part1(input: &str) -> Result<i32, ParseError> {
+    let mut machine = TuringMachine::parse(input)?;
+    Ok(machine.run())
+}
+
+/// Solve part 1, panicking on malformed input
+pub fn solve_part1(input: &str) -> i32 {
+    try_solve_part1(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn solve_part2(_input: &str) -> i32 {
+    // Day 25 typically doesn't have a Part 2 in Advent of Code
+    // It's the final day and usually just requires solving Part 1
+    0
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> u32 {
+        25
+    }
+
+    fn title(&self) -> &'static str {
+        "The Halting Problem"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        TuringMachine::parse(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        try_solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_examples() {
+        let input = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.";
+
+        assert_eq!(solve_part1(input), 3);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the up.
+    - Continue with state B.";
+
+        let err = try_solve_part1(input).unwrap_err();
+        assert_eq!((err.line, err.column), (7, 28));
+        assert_eq!(err.token, "up");
+
+        let input = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state C.";
+        let err = try_solve_part1(input).unwrap_err();
+        assert_eq!((err.line, err.column), (12, 27));
+        assert_eq!(
+            (err.token.as_str(), err.message.as_str()),
+            ("C", "undefined state")
+        );
+
+        let err = try_solve_part1(&input.replacen("state A.", "state B.", 1)).unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (1, "B"));
+
+        let err = try_solve_part1("Begin in state A.").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "unexpected end of input");
+    }
+
+    #[test]
+    fn test_part1_input() {
+        let input = std::fs::read_to_string("src/solutions/y2017/day25/input.txt").unwrap();
+        let result = solve_part1(&input);
+        assert_eq!(result, 2725);
+    }
+}
@@ -0,0 +1,89 @@
+// Advent of Code 2017 - declares the days and their registry
+
+use crate::solutions::{Solution, Year};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// This year's solutions and embedded answers manifest
+pub static YEAR: Year = Year {
+    year: 2017,
+    solutions: SOLUTIONS,
+    answers: include_str!("answers.json"),
+};
+
+/// Registry of all implemented solutions, ordered by day
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered_by_day() {
+        let days: Vec<u32> = SOLUTIONS.iter().map(|s| s.day()).collect();
+        let expected: Vec<u32> = (1..=25).collect();
+        assert_eq!(days, expected);
+    }
+
+    #[test]
+    fn test_inputs_are_embedded() {
+        for solution in SOLUTIONS {
+            assert!(
+                !solution.input().trim().is_empty(),
+                "day {}",
+                solution.day()
+            );
+        }
+        assert_eq!(SOLUTIONS[2].input(), include_str!("day03/input.txt"));
+    }
+}
@@ -0,0 +1,309 @@
+// Integer vectors and bounding boxes in two and three dimensions
+//
+// Vectors work with any primitive integer type; negation and the cross
+// product need a signed one.
+
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An integer type usable as a vector component, signed or not
+pub trait Coord:
+    Copy
+    + Ord
+    + Hash
+    + Default
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    /// `|self - other|`, which never underflows even for unsigned types
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {})*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize);
+impl_coord!(u8, u16, u32, u64, u128, usize);
+
+/// A signed component type, needed for negation and the cross product
+pub trait SignedCoord: Coord + Neg<Output = Self> {}
+
+impl<T: Coord + Neg<Output = T>> SignedCoord for T {}
+
+/// A point or displacement in the plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point or displacement in space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Vec2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Sum of the absolute differences of the components
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Square of the straight-line distance, which stays exact in integers
+    pub fn euclidean_sq(self, other: Self) -> T {
+        let d = Self::new(self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        d.dot(d)
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl<T: Coord> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Sum of the absolute differences of the components
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Square of the straight-line distance, which stays exact in integers
+    pub fn euclidean_sq(self, other: Self) -> T {
+        let d = Self::new(
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        );
+        d.dot(d)
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl<T: SignedCoord> Vec3<T> {
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+/// Component-wise operators for a vector type
+macro_rules! impl_vector_ops {
+    ($name:ident { $($field:ident),+ }) => {
+        impl<T: Coord> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: SignedCoord> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Coord> MulAssign<T> for $name<T> {
+            fn mul_assign(&mut self, factor: T) {
+                *self = *self * factor;
+            }
+        }
+
+        impl<T: Coord> Extent for $name<T> {
+            fn min_each(self, other: Self) -> Self {
+                Self { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            fn max_each(self, other: Self) -> Self {
+                Self { $($field: self.$field.max(other.$field)),+ }
+            }
+
+            fn le_each(self, other: Self) -> bool {
+                true $(&& self.$field <= other.$field)+
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vec2 { x, y });
+impl_vector_ops!(Vec3 { x, y, z });
+
+/// Component-wise comparisons, so that [`Bounds`] works in any dimension
+pub trait Extent: Copy {
+    fn min_each(self, other: Self) -> Self;
+    fn max_each(self, other: Self) -> Self;
+    /// Whether every component is at most the matching one in `other`
+    fn le_each(self, other: Self) -> bool;
+}
+
+/// The smallest axis-aligned box holding a set of points, corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<V> {
+    pub min: V,
+    pub max: V,
+}
+
+impl<V: Extent> Bounds<V> {
+    /// A box holding just `point`
+    pub fn at(point: V) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The box around `points`, or `None` if there are none
+    pub fn from_points(points: impl IntoIterator<Item = V>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::at(points.next()?);
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grow the box to hold `point`
+    pub fn include(&mut self, point: V) {
+        self.min = self.min.min_each(point);
+        self.max = self.max.max_each(point);
+    }
+
+    pub fn contains(&self, point: V) -> bool {
+        self.min.le_each(point) && point.le_each(self.max)
+    }
+}
+
+impl<T: Coord> Bounds<Vec2<T>> {
+    /// `max - min` along each axis
+    pub fn size(&self) -> Vec2<T> {
+        self.max - self.min
+    }
+}
+
+impl<T: Coord> Bounds<Vec3<T>> {
+    /// `max - min` along each axis
+    pub fn size(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_ops() {
+        let a = Vec3::new(1i64, -2, 3);
+        let b = Vec3::new(4, 0, -1);
+        assert_eq!(a + b, Vec3::new(5, -2, 2));
+        assert_eq!(a - b, Vec3::new(-3, -2, 4));
+        assert_eq!(-a * 2, Vec3::new(-2, 4, -6));
+        assert_eq!(a.manhattan(Vec3::zero()), 6);
+        assert_eq!(a.euclidean_sq(b), 29);
+        assert_eq!(a.dot(b), 1);
+        assert_eq!(
+            Vec3::new(1, 0, 0).cross(Vec3::new(0, 1, 0)),
+            Vec3::new(0, 0, 1)
+        );
+
+        let mut v = Vec2::new(3i8, 4);
+        v += Vec2::new(1, 1);
+        v *= 2;
+        v -= Vec2::new(8, 0);
+        assert_eq!(v, Vec2::new(0, 10));
+        assert_eq!(v.euclidean_sq(Vec2::zero()), 100);
+
+        // Unsigned components measure distances without underflowing
+        let a = Vec2::new(2u32, 9);
+        let b = Vec2::new(5, 5);
+        assert_eq!((a.manhattan(b), b.manhattan(a)), (7, 7));
+        assert_eq!(a.euclidean_sq(b), 25);
+        assert_eq!(a + b, Vec2::new(7, 14));
+        assert_eq!(Vec3::new(1usize, 2, 3) * 2, Vec3::new(2, 4, 6));
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(Bounds::<Vec2<i32>>::from_points([]), None);
+        let bounds =
+            Bounds::from_points([Vec2::new(1, 5), Vec2::new(-2, 3), Vec2::new(0, 7)]).unwrap();
+        assert_eq!(bounds.min, Vec2::new(-2, 3));
+        assert_eq!(bounds.max, Vec2::new(1, 7));
+        assert_eq!(bounds.size(), Vec2::new(3, 4));
+        assert!(bounds.contains(Vec2::new(0, 3)));
+        assert!(!bounds.contains(Vec2::new(2, 3)));
+
+        let grid = Bounds::from_points([Vec2::new(3usize, 1), Vec2::new(0, 4)]).unwrap();
+        assert_eq!(grid.size(), Vec2::new(3, 3));
+
+        let mut cube = Bounds::at(Vec3::new(0i64, 0, 0));
+        cube.include(Vec3::new(-1, 2, 3));
+        assert!(cube.contains(Vec3::new(0, 1, 3)));
+        assert!(!cube.contains(Vec3::new(0, 1, 4)));
+    }
+}
@@ -1,10 +1,21 @@
 // Graph algorithms and utilities
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
 
 /// Basic graph representation
 pub type Graph<T> = HashMap<T, Vec<T>>;
 
+/// Graph whose edges carry a weight: node -> [(neighbour, weight)]
+pub type WeightedGraph<T, W> = HashMap<T, Vec<(T, W)>>;
+
+/// Edge weight or path cost; `Default` must be zero
+pub trait Weight: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<W: Copy + Ord + Default + Add<Output = W>> Weight for W {}
+
 /// Breadth-first search
 pub fn bfs<T: Clone + Eq + std::hash::Hash>(
     graph: &Graph<T>,
@@ -71,3 +82,644 @@ pub fn find_reachable<T: Clone + Eq + std::hash::Hash>(graph: &Graph<T>, start:
 
     visited
 }
+
+/// Cheapest path from `start` to `target` and its cost
+pub fn dijkstra<T: Clone + Eq + Hash, W: Weight>(
+    graph: &WeightedGraph<T, W>,
+    start: &T,
+    target: &T,
+) -> Option<(W, Vec<T>)> {
+    astar(
+        start,
+        |node| node == target,
+        |node| graph.get(node).cloned().unwrap_or_default(),
+        |_| W::default(),
+    )
+}
+
+/// Cost of the cheapest path from `start` to every reachable node
+pub fn distances<T: Clone + Eq + Hash, W: Weight>(
+    graph: &WeightedGraph<T, W>,
+    start: &T,
+) -> HashMap<T, W> {
+    let mut nodes = Interner::default();
+    let mut best: Vec<W> = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    let start_id = nodes.id(start, &mut best, W::default());
+    heap.push(Reverse((W::default(), start_id)));
+
+    while let Some(Reverse((cost, id))) = heap.pop() {
+        if cost > best[id] {
+            continue;
+        }
+        let node = nodes.node(id).clone();
+        for (next, weight) in graph.get(&node).into_iter().flatten() {
+            let next_cost = cost + *weight;
+            let is_new = nodes.get(next).is_none();
+            let next_id = nodes.id(next, &mut best, next_cost);
+            if is_new || next_cost < best[next_id] {
+                best[next_id] = next_cost;
+                heap.push(Reverse((next_cost, next_id)));
+            }
+        }
+    }
+
+    nodes.into_nodes().into_iter().zip(best).collect()
+}
+
+/// A* search over an implicit graph
+///
+/// `neighbors` lists the edges out of a node with their weights, `is_target`
+/// recognises the goal and `heuristic` must never overestimate the remaining
+/// cost. Nodes reached again more cheaply are expanded again, so the
+/// heuristic need not be consistent. Returns the cost of the cheapest path
+/// and the nodes along it, including both ends.
+pub fn astar<T, W, I>(
+    start: &T,
+    mut is_target: impl FnMut(&T) -> bool,
+    mut neighbors: impl FnMut(&T) -> I,
+    mut heuristic: impl FnMut(&T) -> W,
+) -> Option<(W, Vec<T>)>
+where
+    T: Clone + Eq + Hash,
+    W: Weight,
+    I: IntoIterator<Item = (T, W)>,
+{
+    let mut nodes = Interner::default();
+    let mut best: Vec<W> = Vec::new();
+    let mut parent: HashMap<usize, usize> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    let start_id = nodes.id(start, &mut best, W::default());
+    heap.push(Reverse((heuristic(start), W::default(), start_id)));
+
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        // Skip entries superseded by a cheaper route to the same node
+        if cost > best[id] {
+            continue;
+        }
+        let node = nodes.node(id).clone();
+        if is_target(&node) {
+            let mut path = vec![node];
+            let mut current = id;
+            while let Some(&previous) = parent.get(&current) {
+                path.push(nodes.node(previous).clone());
+                current = previous;
+            }
+            path.reverse();
+            return Some((best[id], path));
+        }
+
+        for (next, weight) in neighbors(&node) {
+            let next_cost = cost + weight;
+            let is_new = nodes.get(&next).is_none();
+            let next_id = nodes.id(&next, &mut best, next_cost);
+            if is_new || next_cost < best[next_id] {
+                best[next_id] = next_cost;
+                parent.insert(next_id, id);
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, next_id)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Numbers nodes so that the priority queue only has to order numbers
+struct Interner<T> {
+    ids: HashMap<T, usize>,
+    nodes: Vec<T>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            nodes: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> Interner<T> {
+    fn get(&self, node: &T) -> Option<usize> {
+        self.ids.get(node).copied()
+    }
+
+    /// The id of `node`, registering it with `initial` in `values` if it is new
+    fn id<V>(&mut self, node: &T, values: &mut Vec<V>, initial: V) -> usize {
+        if let Some(id) = self.get(node) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.ids.insert(node.clone(), id);
+        self.nodes.push(node.clone());
+        values.push(initial);
+        id
+    }
+
+    fn node(&self, id: usize) -> &T {
+        &self.nodes[id]
+    }
+
+    fn into_nodes(self) -> Vec<T> {
+        self.nodes
+    }
+}
+
+/// Every node of a graph, including those that only appear as neighbours
+fn all_nodes<T: Clone + Eq + Hash>(graph: &Graph<T>) -> Vec<T> {
+    let mut seen = HashSet::new();
+    let mut nodes = Vec::new();
+    for (node, neighbors) in graph {
+        for n in std::iter::once(node).chain(neighbors) {
+            if seen.insert(n) {
+                nodes.push(n.clone());
+            }
+        }
+    }
+    nodes
+}
+
+/// Order the nodes so that every edge points forwards, or `None` if the
+/// graph has a cycle; ties are broken by taking the smallest node first
+pub fn topological_sort<T: Clone + Ord + Hash>(graph: &Graph<T>) -> Option<Vec<T>> {
+    let nodes = all_nodes(graph);
+    let mut in_degree: HashMap<&T, usize> = nodes.iter().map(|n| (n, 0)).collect();
+    for neighbors in graph.values() {
+        for n in neighbors {
+            *in_degree.entry(n).or_default() += 1;
+        }
+    }
+
+    let mut ready: BTreeSet<&T> = in_degree
+        .iter()
+        .filter(|(_, &d)| d == 0)
+        .map(|(&n, _)| n)
+        .collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(node) = ready.pop_first() {
+        order.push(node.clone());
+        for n in graph.get(node).into_iter().flatten() {
+            let degree = in_degree.entry(n).or_default();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.insert(n);
+            }
+        }
+    }
+
+    (order.len() == nodes.len()).then_some(order)
+}
+
+/// A directed cycle in the graph, as the nodes around it, if there is one
+pub fn find_cycle<T: Clone + Eq + Hash>(graph: &Graph<T>) -> Option<Vec<T>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        OnStack,
+        Done,
+    }
+
+    let mut state: HashMap<T, State> = HashMap::new();
+    for root in all_nodes(graph) {
+        if state.contains_key(&root) {
+            continue;
+        }
+        // Iterative depth-first search; `stack` is the current path
+        let mut stack: Vec<(T, usize)> = vec![(root.clone(), 0)];
+        state.insert(root, State::OnStack);
+        while let Some((node, next)) = stack.last_mut() {
+            let neighbors = graph.get(node).map(Vec::as_slice).unwrap_or_default();
+            let Some(child) = neighbors.get(*next).cloned() else {
+                state.insert(node.clone(), State::Done);
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            match state.get(&child) {
+                Some(State::OnStack) => {
+                    let at = stack.iter().position(|(n, _)| *n == child).unwrap_or(0);
+                    return Some(stack[at..].iter().map(|(n, _)| n.clone()).collect());
+                }
+                Some(State::Done) => {}
+                None => {
+                    state.insert(child.clone(), State::OnStack);
+                    stack.push((child, 0));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Strongly connected components, using Tarjan's algorithm
+///
+/// Components come out in reverse topological order: no component has an
+/// edge into one listed after it.
+pub fn strongly_connected_components<T: Clone + Eq + Hash>(graph: &Graph<T>) -> Vec<Vec<T>> {
+    let mut index: HashMap<&T, usize> = HashMap::new();
+    let mut low: HashMap<&T, usize> = HashMap::new();
+    let mut stack: Vec<&T> = Vec::new();
+    let mut on_stack: HashSet<&T> = HashSet::new();
+    let mut components = Vec::new();
+
+    let nodes: Vec<&T> = graph
+        .iter()
+        .flat_map(|(node, neighbors)| std::iter::once(node).chain(neighbors))
+        .collect();
+    for root in nodes {
+        if index.contains_key(root) {
+            continue;
+        }
+
+        // Each frame is a node and how many of its edges have been followed
+        let mut frames: Vec<(&T, usize)> = vec![(root, 0)];
+        while let Some(&mut (node, ref mut edge)) = frames.last_mut() {
+            if *edge == 0 && !index.contains_key(node) {
+                let node_index = index.len();
+                index.insert(node, node_index);
+                low.insert(node, node_index);
+                stack.push(node);
+                on_stack.insert(node);
+            }
+
+            let neighbors = graph.get(node).map_or(&[][..], Vec::as_slice);
+            if let Some(next) = neighbors.get(*edge) {
+                *edge += 1;
+                if !index.contains_key(next) {
+                    frames.push((next, 0));
+                } else if on_stack.contains(next) {
+                    let node_low = low[node].min(index[next]);
+                    low.insert(node, node_low);
+                }
+                continue;
+            }
+
+            // All edges followed: close the component rooted here, if any
+            frames.pop();
+            if low[node] == index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack.remove(member);
+                    component.push(member.clone());
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+            if let Some(&(parent, _)) = frames.last() {
+                let parent_low = low[parent].min(low[node]);
+                low.insert(parent, parent_low);
+            }
+        }
+    }
+    components
+}
+
+/// Minimum spanning forest of an undirected weighted graph, using Prim's
+/// algorithm; edges may be listed in one or both directions
+pub fn minimum_spanning_tree<T: Clone + Eq + Hash, W: Weight>(
+    graph: &WeightedGraph<T, W>,
+) -> Vec<(T, T, W)> {
+    // Make every edge visible from both ends
+    let mut undirected: HashMap<&T, Vec<(&T, W)>> = HashMap::new();
+    for (from, edges) in graph {
+        undirected.entry(from).or_default();
+        for (to, weight) in edges {
+            undirected.entry(from).or_default().push((to, *weight));
+            undirected.entry(to).or_default().push((from, *weight));
+        }
+    }
+
+    let nodes: Vec<&T> = undirected.keys().copied().collect();
+    let id: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let mut in_tree = vec![false; nodes.len()];
+    let mut tree = Vec::new();
+
+    for root in 0..nodes.len() {
+        if in_tree[root] {
+            continue;
+        }
+        // Heap entries are (weight, to, from) with nodes as ids
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((W::default(), root, root)));
+        while let Some(Reverse((weight, to, from))) = heap.pop() {
+            if in_tree[to] {
+                continue;
+            }
+            in_tree[to] = true;
+            if to != from {
+                tree.push((nodes[from].clone(), nodes[to].clone(), weight));
+            }
+            for &(next, w) in &undirected[nodes[to]] {
+                if !in_tree[id[next]] {
+                    heap.push(Reverse((w, id[next], to)));
+                }
+            }
+        }
+    }
+    tree
+}
+
+/// Disjoint sets over `0..n`, with union by size and path compression
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// `n` singleton sets
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            sets: n,
+        }
+    }
+
+    /// Representative of the set holding `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point everything on the way straight at the root
+        let mut current = x;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merge the sets holding `a` and `b`; false if they were already one set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set holding `x`
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Number of disjoint sets
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Members of every set, each in increasing order, sets ordered by smallest member
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for x in 0..self.parent.len() {
+            let root = self.find(x);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(x);
+        }
+        groups
+    }
+}
+
+/// The connected components of a graph, treating every edge as undirected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components<T: Eq + Hash> {
+    /// Members of each component, sorted, components ordered by smallest member
+    pub groups: Vec<Vec<T>>,
+    membership: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> Components<T> {
+    /// Number of components
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Index into `groups` of the component holding `node`
+    pub fn component_of(&self, node: &T) -> Option<usize> {
+        self.membership.get(node).copied()
+    }
+
+    /// Members of the component holding `node`
+    pub fn group_of(&self, node: &T) -> &[T] {
+        self.component_of(node)
+            .map_or(&[], |i| self.groups[i].as_slice())
+    }
+}
+
+/// Split a graph into connected components, nodes that only appear as
+/// neighbours included
+pub fn connected_components<T: Clone + Ord + Hash>(graph: &Graph<T>) -> Components<T> {
+    let mut nodes = all_nodes(graph);
+    nodes.sort();
+    let index: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+
+    let mut sets = UnionFind::new(nodes.len());
+    for (node, neighbors) in graph {
+        for neighbor in neighbors {
+            sets.union(index[node], index[neighbor]);
+        }
+    }
+
+    let groups: Vec<Vec<T>> = sets
+        .groups()
+        .into_iter()
+        .map(|group| group.into_iter().map(|i| nodes[i].clone()).collect())
+        .collect();
+    let membership = groups
+        .iter()
+        .enumerate()
+        .flat_map(|(i, group)| group.iter().map(move |n| (n.clone(), i)))
+        .collect();
+    Components { groups, membership }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(u32, u32)]) -> Graph<u32> {
+        let mut graph = Graph::new();
+        for &(from, to) in edges {
+            graph.entry(from).or_insert_with(Vec::new).push(to);
+        }
+        graph
+    }
+
+    fn weighted(edges: &[(char, char, u32)]) -> WeightedGraph<char, u32> {
+        let mut graph = WeightedGraph::new();
+        for &(from, to, weight) in edges {
+            graph
+                .entry(from)
+                .or_insert_with(Vec::new)
+                .push((to, weight));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_unweighted_search() {
+        let graph = graph(&[(0, 1), (1, 2), (0, 3), (3, 2), (2, 4)]);
+        assert_eq!(bfs(&graph, &0, &4).map(|path| path.len()), Some(4));
+        assert_eq!(bfs(&graph, &4, &0), None);
+        assert_eq!(find_reachable(&graph, &3).len(), 3);
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let graph = weighted(&[
+            ('a', 'b', 7),
+            ('a', 'c', 2),
+            ('c', 'b', 3),
+            ('b', 'd', 1),
+            ('c', 'd', 8),
+        ]);
+        assert_eq!(
+            dijkstra(&graph, &'a', &'d'),
+            Some((6, vec!['a', 'c', 'b', 'd']))
+        );
+        assert_eq!(dijkstra(&graph, &'d', &'a'), None);
+
+        let costs = distances(&graph, &'a');
+        assert_eq!(costs.len(), 4);
+        assert_eq!((costs[&'a'], costs[&'b'], costs[&'d']), (0, 5, 6));
+
+        // Walk a number line towards 10, where steps of +3 cost 2 and +1 cost 1
+        let (cost, path) = astar(
+            &0i32,
+            |&n| n == 10,
+            |&n| [(n + 3, 2), (n + 1, 1)],
+            |&n| (10 - n).max(0) / 3,
+        )
+        .unwrap();
+        assert_eq!((cost, path.len()), (7, 5));
+
+        // An admissible but inconsistent heuristic makes 'b' look closer
+        // than it is, so it is first expanded along the dearer edge
+        let graph = weighted(&[('s', 'a', 1), ('s', 'b', 3), ('a', 'b', 1), ('b', 'g', 5)]);
+        let result = astar(
+            &'s',
+            |&n| n == 'g',
+            |n| graph.get(n).cloned().unwrap_or_default(),
+            |&n| if n == 'a' { 6 } else { 0 },
+        );
+        assert_eq!(result, Some((7, vec!['s', 'a', 'b', 'g'])));
+    }
+
+    #[test]
+    fn test_ordering_and_cycles() {
+        let dag = graph(&[
+            (5, 11),
+            (7, 11),
+            (7, 8),
+            (3, 8),
+            (3, 10),
+            (11, 2),
+            (11, 9),
+            (8, 9),
+        ]);
+        let order = topological_sort(&dag).unwrap();
+        assert_eq!(order, [3, 5, 7, 8, 10, 11, 2, 9]);
+        assert_eq!(find_cycle(&dag), None);
+
+        let cyclic = graph(&[(1, 2), (2, 3), (3, 1), (3, 4)]);
+        assert_eq!(topological_sort(&cyclic), None);
+        let mut cycle = find_cycle(&cyclic).unwrap();
+        cycle.sort();
+        assert_eq!(cycle, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = graph(&[(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (6, 5)]);
+        let mut components: Vec<Vec<u32>> = strongly_connected_components(&graph)
+            .into_iter()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        assert_eq!(components.len(), 3);
+        // {4, 5} has no edges out, so it comes before {1, 2, 3}
+        let sink = components.iter().position(|c| c == &[4, 5]).unwrap();
+        let source = components.iter().position(|c| c == &[1, 2, 3]).unwrap();
+        assert!(sink < source);
+        components.sort();
+        assert_eq!(components, [vec![1, 2, 3], vec![4, 5], vec![6]]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components_long_chain() {
+        let edges: Vec<(u32, u32)> = (0..100_000).map(|n| (n, n + 1)).collect();
+        let components = strongly_connected_components(&graph(&edges));
+        assert_eq!(components.len(), 100_001);
+        assert!(components.iter().all(|c| c.len() == 1));
+        // Sinks come first, so the end of the chain leads
+        assert_eq!(components[0], [100_000]);
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.set_size(2), 4);
+        assert_eq!(sets.sets(), 3);
+        assert_eq!(sets.groups(), [vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let graph = graph(&[(4, 2), (2, 7), (9, 9), (5, 1)]);
+        let components = connected_components(&graph);
+        assert_eq!(components.groups, [vec![1, 5], vec![2, 4, 7], vec![9]]);
+        assert_eq!(components.len(), 3);
+        assert_eq!(components.component_of(&7), Some(1));
+        assert_eq!(components.group_of(&5), [1, 5]);
+        assert!(components.group_of(&3).is_empty());
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let graph = weighted(&[
+            ('a', 'b', 4),
+            ('a', 'c', 1),
+            ('c', 'b', 2),
+            ('b', 'd', 5),
+            ('c', 'd', 8),
+            ('x', 'y', 3),
+        ]);
+        let tree = minimum_spanning_tree(&graph);
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.iter().map(|&(_, _, w)| w).sum::<u32>(), 11);
+    }
+}
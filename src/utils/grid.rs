@@ -1,5 +1,10 @@
 // 2D grid utilities
 
+use crate::utils::input::{self, ParseError};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point {
     pub x: i32,
@@ -7,7 +12,595 @@ pub struct Point {
 }
 
 impl Point {
-    pub fn new(x: i32, y: i32) -> Self {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
         Self { x, y }
     }
+
+    /// Taxicab distance: steps needed when moving only orthogonally
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Chessboard distance: steps needed when diagonal moves are allowed
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Up, right, down and left of this point, with y growing downwards
+    pub fn neighbors4(self) -> [Point; 4] {
+        Direction::ALL.map(|d| self + d)
+    }
+
+    /// All eight surrounding points, clockwise from up
+    pub fn neighbors8(self) -> [Point; 8] {
+        Direction8::ALL.map(|d| self + d)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+/// One of the four orthogonal directions, on a grid where y grows downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The unit step in this direction
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the eight compass directions, on a grid where y grows downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The unit step in this direction
+    pub fn offset(self) -> Point {
+        match self {
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// An eighth of a turn anticlockwise
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// An eighth of a turn clockwise
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction8) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl AddAssign<Direction8> for Point {
+    fn add_assign(&mut self, direction: Direction8) {
+        *self = *self + direction;
+    }
+}
+
+/// A dense, fixed-size grid stored row by row; `(0, 0)` is the top-left cell
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Build a grid from rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must all have {} cells",
+            width
+        );
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Build a grid from rows of any length, padding short rows with `fill`
+    pub fn from_rows_padded(rows: Vec<Vec<T>>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let rows = rows
+            .into_iter()
+            .map(|mut row| {
+                row.resize(width, fill.clone());
+                row
+            })
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    /// Parse a rectangular block of text, converting each character with `cell`
+    pub fn parse_with(input: &str, cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut cell = cell;
+        let rows = input::rectangular_grid(input)?
+            .into_iter()
+            .map(|row| row.into_iter().map(&mut cell).collect())
+            .collect();
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    /// The cell at `p`, or `None` outside the grid
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The orthogonal neighbours of `p` that lie inside the grid
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// The orthogonal and diagonal neighbours of `p` that lie inside the grid
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// Number of cells matching `predicate`
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.cells.iter().filter(|cell| predicate(cell)).count()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Render each cell as one character, one line per row
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows().take(self.height) {
+            out.extend(row.iter().map(&cell));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid rotated a quarter turn clockwise
+    pub fn rotate(&self) -> Self {
+        let rows = (0..self.width)
+            .map(|x| {
+                (0..self.height)
+                    .rev()
+                    .map(|y| self.cells[y * self.width + x].clone())
+                    .collect()
+            })
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    /// The grid mirrored left to right
+    pub fn flip(&self) -> Self {
+        let rows = self
+            .rows()
+            .map(|row| row.iter().rev().cloned().collect())
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    /// All eight rotations and reflections, starting with the grid itself
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut result = Vec::with_capacity(8);
+        let mut current = self.clone();
+        for _ in 0..4 {
+            let (flipped, rotated) = (current.flip(), current.rotate());
+            result.push(current);
+            result.push(flipped);
+            current = rotated;
+        }
+        result
+    }
+
+    /// The `width` x `height` block whose top-left corner is `origin`
+    pub fn block(&self, origin: Point, width: usize, height: usize) -> Self {
+        let rows = (0..height as i32)
+            .map(|dy| {
+                (0..width as i32)
+                    .map(|dx| self[origin + Point::new(dx, dy)].clone())
+                    .collect()
+            })
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    /// Copy `block` into this grid with its top-left corner at `origin`
+    pub fn paste(&mut self, origin: Point, block: &Grid<T>) {
+        for (p, cell) in block.iter() {
+            self[origin + p] = cell.clone();
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parse a rectangular block of text into a grid of its characters
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, |ch| ch)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&ch| ch))
+    }
+}
+
+/// An unbounded grid that only stores cells differing from a default value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// An empty grid where every cell reads as `default`
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Copy a dense grid with its top-left corner at `origin`, skipping default cells
+    pub fn from_grid(grid: &Grid<T>, origin: Point, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        for (p, cell) in grid.iter() {
+            sparse.set(origin + p, cell.clone());
+        }
+        sparse
+    }
+
+    pub fn get(&self, p: Point) -> &T {
+        self.cells.get(&p).unwrap_or(&self.default)
+    }
+
+    /// Set a cell; setting it to the default value forgets it
+    pub fn set(&mut self, p: Point, value: T) {
+        if value == self.default {
+            self.cells.remove(&p);
+        } else {
+            self.cells.insert(p, value);
+        }
+    }
+
+    /// Number of cells that differ from the default
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every non-default cell, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+
+    /// Top-left and bottom-right corners of the non-default cells
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+
+    /// Render the cells from `min` to `max` inclusive, one line per row
+    pub fn render(&self, min: Point, max: Point, cell: impl Fn(Point, &T) -> String) -> String {
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let p = Point::new(x, y);
+                out += &cell(p, self.get(p));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(3, -4);
+        let b = Point::new(1, 2);
+        assert_eq!(a + b, Point::new(4, -2));
+        assert_eq!(a - b, Point::new(2, -6));
+        assert_eq!(-a * 2, Point::new(-6, 8));
+        assert_eq!(a.manhattan(Point::ORIGIN), 7);
+        assert_eq!(a.chebyshev(b), 6);
+
+        let mut p = Point::ORIGIN;
+        p += Direction::Up;
+        p += Direction8::SouthEast;
+        assert_eq!(p, Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.reverse(), Direction8::NorthEast);
+        assert_eq!(Direction8::from(Direction::Down), Direction8::South);
+        for d in Direction::ALL {
+            assert_eq!(Direction8::from(d).offset(), d.offset());
+        }
+    }
+
+    #[test]
+    fn test_grid_access() {
+        let grid = Grid::parse("ab\ncd\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.count(|&ch| ch > 'b'), 2);
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+        assert!(Grid::parse("ab\nc\n").is_err());
+
+        let padded = Grid::from_rows_padded(vec![vec!['|'], vec![' ', '+']], ' ');
+        assert_eq!(padded.render(|&ch| ch), "| \n +\n");
+    }
+
+    #[test]
+    fn test_grid_transforms() {
+        let grid = Grid::parse(".#.\n..#\n###").unwrap();
+        assert_eq!(grid.rotate().to_string(), "#..\n#.#\n##.\n");
+        assert_eq!(grid.flip().to_string(), ".#.\n#..\n###\n");
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert_eq!(orientations[1], grid.flip());
+
+        let mut big = Grid::new(4, 4, '.');
+        big.paste(Point::new(2, 2), &Grid::parse("ab\ncd").unwrap());
+        assert_eq!(big.block(Point::new(1, 1), 2, 2).to_string(), "..\n.a\n");
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let dense = Grid::parse("..#\n#..\n...").unwrap();
+        let mut sparse = SparseGrid::from_grid(&dense, Point::new(-1, -1), '.');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(*sparse.get(Point::new(1, -1)), '#');
+        assert_eq!(*sparse.get(Point::new(100, 100)), '.');
+        assert_eq!(
+            sparse.bounds(),
+            Some((Point::new(-1, -1), Point::new(1, 0)))
+        );
+
+        sparse.set(Point::new(1, -1), '.');
+        assert_eq!(sparse.len(), 1);
+        assert_eq!(
+            sparse.render(Point::new(-1, 0), Point::new(0, 0), |_, ch| ch.to_string()),
+            "#.\n"
+        );
+    }
 }
@@ -0,0 +1,384 @@
+// Hexagonal grid coordinates
+//
+// Cells are stored in axial coordinates `(q, r)`; the third cube coordinate
+// is `s = -q - r`. Directions, rings and distances do not depend on how the
+// hexagons are drawn, so orientation only matters when naming directions and
+// converting to or from offset (row/column) coordinates.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A hexagon in axial coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    /// Unit steps to the six neighbours, anticlockwise
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex { q: 1, r: 0 },
+        Hex { q: 1, r: -1 },
+        Hex { q: 0, r: -1 },
+        Hex { q: -1, r: 0 },
+        Hex { q: -1, r: 1 },
+        Hex { q: 0, r: 1 },
+    ];
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// The third cube coordinate
+    pub fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Cube coordinates `(q, r, s)`, which always sum to 0
+    pub fn cube(self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    /// Build from cube coordinates, which must sum to 0
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Option<Self> {
+        (q + r + s == 0).then_some(Self::new(q, r))
+    }
+
+    /// Number of steps from the origin
+    pub fn length(self) -> i32 {
+        self.q.abs().max(self.r.abs()).max(self.s().abs())
+    }
+
+    /// Number of steps between two hexagons
+    pub fn distance(self, other: Hex) -> i32 {
+        (self - other).length()
+    }
+
+    pub fn neighbors(self) -> [Hex; 6] {
+        Hex::DIRECTIONS.map(|d| self + d)
+    }
+
+    /// The hexagons exactly `radius` steps from `self`, going round anticlockwise
+    pub fn ring(self, radius: i32) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![self];
+        }
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut hex = self + Hex::DIRECTIONS[4] * radius;
+        for direction in Hex::DIRECTIONS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += direction;
+            }
+        }
+        ring
+    }
+
+    /// The hexagons within `radius` steps of `self`, ring by ring from the centre
+    pub fn spiral(self, radius: i32) -> Vec<Hex> {
+        (0..=radius).flat_map(|k| self.ring(k)).collect()
+    }
+
+    /// The hexagons a straight line from `self` to `other` passes through,
+    /// including both ends
+    pub fn line_to(self, other: Hex) -> Vec<Hex> {
+        let n = self.distance(other);
+        if n == 0 {
+            return vec![self];
+        }
+        // Nudge the end points so that lines along cell edges round consistently
+        let (aq, ar) = (self.q as f64 + 1e-6, self.r as f64 + 1e-6);
+        let (bq, br) = (other.q as f64 + 1e-6, other.r as f64 + 1e-6);
+        (0..=n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                round(aq + (bq - aq) * t, ar + (br - ar) * t)
+            })
+            .collect()
+    }
+
+    /// A shortest sequence of unit steps from `self` to `goal` on an open grid
+    pub fn steps_to(self, goal: Hex) -> Vec<Hex> {
+        let mut steps = Vec::with_capacity(self.distance(goal) as usize);
+        let mut remaining = goal - self;
+        while remaining != Hex::ORIGIN {
+            // Some direction always brings the goal one step closer
+            let step = Hex::DIRECTIONS
+                .into_iter()
+                .min_by_key(|&d| (remaining - d).length())
+                .unwrap_or_default();
+            steps.push(step);
+            remaining -= step;
+        }
+        steps
+    }
+
+    /// A shortest sequence of unit steps from `self` to `goal` that only
+    /// enters hexagons accepted by `passable`, searching at most `max_steps`
+    /// deep; `None` if there is no such route
+    pub fn find_path(
+        self,
+        goal: Hex,
+        max_steps: usize,
+        passable: impl Fn(Hex) -> bool,
+    ) -> Option<Vec<Hex>> {
+        let mut came_from: HashMap<Hex, Hex> = HashMap::new();
+        let mut seen = HashSet::from([self]);
+        let mut queue = VecDeque::from([(self, 0)]);
+
+        while let Some((hex, depth)) = queue.pop_front() {
+            if hex == goal {
+                let mut steps = Vec::new();
+                let mut current = goal;
+                while let Some(&previous) = came_from.get(&current) {
+                    steps.push(current - previous);
+                    current = previous;
+                }
+                steps.reverse();
+                return Some(steps);
+            }
+            if depth == max_steps {
+                continue;
+            }
+            for next in hex.neighbors() {
+                if passable(next) && seen.insert(next) {
+                    came_from.insert(next, hex);
+                    queue.push_back((next, depth + 1));
+                }
+            }
+        }
+        None
+    }
+
+    /// Offset coordinates for this hexagon in the given layout
+    pub fn to_offset(self, layout: Layout) -> Offset {
+        match layout.orientation {
+            Orientation::FlatTop => Offset {
+                col: self.q,
+                row: self.r + layout.parity.shift(self.q),
+            },
+            Orientation::PointyTop => Offset {
+                col: self.q + layout.parity.shift(self.r),
+                row: self.r,
+            },
+        }
+    }
+
+    /// The hexagon at the given offset coordinates in the given layout
+    pub fn from_offset(offset: Offset, layout: Layout) -> Self {
+        match layout.orientation {
+            Orientation::FlatTop => {
+                Hex::new(offset.col, offset.row - layout.parity.shift(offset.col))
+            }
+            Orientation::PointyTop => {
+                Hex::new(offset.col - layout.parity.shift(offset.row), offset.row)
+            }
+        }
+    }
+}
+
+/// The nearest hexagon to fractional axial coordinates
+fn round(q: f64, r: f64) -> Hex {
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    // Recompute whichever coordinate rounded furthest from the others
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+    Hex::new(rq as i32, rr as i32)
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i32) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Hex) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, other: Hex) {
+        *self = *self - other;
+    }
+}
+
+/// How the hexagons are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Flat edges at top and bottom; neighbours are n, ne, se, s, sw, nw
+    FlatTop,
+    /// Points at top and bottom; neighbours are e, ne, nw, w, sw, se
+    PointyTop,
+}
+
+impl Orientation {
+    /// Compass names of [`Hex::DIRECTIONS`], in the same order
+    pub fn direction_names(self) -> [&'static str; 6] {
+        match self {
+            Orientation::FlatTop => ["se", "ne", "n", "nw", "sw", "s"],
+            Orientation::PointyTop => ["e", "ne", "nw", "w", "sw", "se"],
+        }
+    }
+
+    /// The unit step for a compass name such as `ne`
+    pub fn direction(self, name: &str) -> Option<Hex> {
+        let index = self.direction_names().iter().position(|&n| n == name)?;
+        Some(Hex::DIRECTIONS[index])
+    }
+
+    /// The compass name of a unit step
+    pub fn direction_name(self, step: Hex) -> Option<&'static str> {
+        let index = Hex::DIRECTIONS.iter().position(|&d| d == step)?;
+        Some(self.direction_names()[index])
+    }
+}
+
+/// Which alternate columns (flat-top) or rows (pointy-top) are shoved over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Parity {
+    Odd,
+    Even,
+}
+
+impl Parity {
+    /// How far line `n` is shoved, in cells
+    fn shift(self, n: i32) -> i32 {
+        match self {
+            Parity::Odd => (n - (n & 1)) / 2,
+            Parity::Even => (n + (n & 1)) / 2,
+        }
+    }
+}
+
+/// An offset coordinate system: orientation plus which lines are shifted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Layout {
+    pub orientation: Orientation,
+    pub parity: Parity,
+}
+
+/// Column and row of a hexagon in an offset layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Offset {
+    pub col: i32,
+    pub row: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinates() {
+        let hex = Hex::new(2, -3);
+        assert_eq!(hex.cube(), (2, -3, 1));
+        assert_eq!(Hex::from_cube(2, -3, 1), Some(hex));
+        assert_eq!(Hex::from_cube(2, -3, 0), None);
+        assert_eq!(hex.length(), 3);
+        assert_eq!(hex.distance(Hex::new(-1, 0)), 3);
+        assert_eq!(-hex * 2 + hex, Hex::new(-2, 3));
+
+        for orientation in [Orientation::FlatTop, Orientation::PointyTop] {
+            for parity in [Parity::Odd, Parity::Even] {
+                let layout = Layout {
+                    orientation,
+                    parity,
+                };
+                for hex in Hex::ORIGIN.spiral(3) {
+                    assert_eq!(Hex::from_offset(hex.to_offset(layout), layout), hex);
+                }
+            }
+        }
+        let odd_q = Layout {
+            orientation: Orientation::FlatTop,
+            parity: Parity::Odd,
+        };
+        assert_eq!(Hex::new(1, 0).to_offset(odd_q), Offset { col: 1, row: 0 });
+        assert_eq!(Hex::new(1, -1).to_offset(odd_q), Offset { col: 1, row: -1 });
+    }
+
+    #[test]
+    fn test_directions() {
+        let flat = Orientation::FlatTop;
+        assert_eq!(flat.direction("n"), Some(Hex::new(0, -1)));
+        assert_eq!(flat.direction("e"), None);
+        assert_eq!(flat.direction_name(Hex::new(0, 1)), Some("s"));
+        assert_eq!(Orientation::PointyTop.direction("w"), Some(Hex::new(-1, 0)));
+        for hex in Hex::ORIGIN.neighbors() {
+            assert_eq!(hex.length(), 1);
+        }
+    }
+
+    #[test]
+    fn test_rings_and_lines() {
+        let ring = Hex::new(1, 1).ring(2);
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|hex| hex.distance(Hex::new(1, 1)) == 2));
+        assert_eq!(Hex::ORIGIN.spiral(2).len(), 19);
+
+        let line = Hex::ORIGIN.line_to(Hex::new(3, -1));
+        assert_eq!(line.len(), 4);
+        assert_eq!(line.first(), Some(&Hex::ORIGIN));
+        assert_eq!(line.last(), Some(&Hex::new(3, -1)));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+    }
+
+    #[test]
+    fn test_paths() {
+        let goal = Hex::new(3, -5);
+        let steps = Hex::ORIGIN.steps_to(goal);
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps.iter().fold(Hex::ORIGIN, |a, &b| a + b), goal);
+
+        // A wall around the origin's eastern side forces a detour
+        let wall = [Hex::new(1, -1), Hex::new(1, 0), Hex::new(0, 1)];
+        let steps = Hex::ORIGIN
+            .find_path(Hex::new(2, 0), 10, |hex| !wall.contains(&hex))
+            .unwrap();
+        assert_eq!(steps.len(), 5);
+        assert_eq!(
+            steps.iter().fold(Hex::ORIGIN, |a, &b| a + b),
+            Hex::new(2, 0)
+        );
+        assert_eq!(
+            Hex::ORIGIN.find_path(Hex::new(2, 0), 4, |hex| !wall.contains(&hex)),
+            None
+        );
+    }
+}
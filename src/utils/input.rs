@@ -1,13 +1,483 @@
 // Input parsing utilities
 
+use flate2::read::GzDecoder;
+use std::fmt;
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-/// Read input file as string
-pub fn read_input(file_path: &str) -> Result<String, Box<dyn std::error::Error>> {
-    Ok(fs::read_to_string(file_path)?)
+/// The first two bytes of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Error raised when puzzle input cannot be read
+#[derive(Debug)]
+pub enum InputError {
+    /// Reading a file or stdin failed
+    Io { source: String, error: io::Error },
+    /// A gzip stream could not be decompressed
+    Gzip { source: String, error: io::Error },
+    /// The input is not valid UTF-8
+    NotUtf8 { source: String },
+    /// None of the candidate files exist
+    NotFound { tried: Vec<PathBuf> },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io { source, error } => write!(f, "failed to read {}: {}", source, error),
+            InputError::Gzip { source, error } => {
+                write!(f, "failed to decompress {}: {}", source, error)
+            }
+            InputError::NotUtf8 { source } => write!(f, "{} is not valid UTF-8", source),
+            InputError::NotFound { tried } => {
+                let tried: Vec<String> = tried.iter().map(|p| p.display().to_string()).collect();
+                write!(f, "no input found (tried {})", tried.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { error, .. } | InputError::Gzip { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Read puzzle input from a file, or from stdin when the path is `-`
+///
+/// Gzip-compressed input is decompressed transparently, whatever the file is
+/// called. If the file does not exist but `PATH.gz` does, that is read instead.
+pub fn read_input(file_path: &str) -> Result<String, InputError> {
+    if file_path == "-" {
+        let mut bytes = Vec::new();
+        io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|error| InputError::Io {
+                source: "stdin".to_string(),
+                error,
+            })?;
+        return decode(bytes, "stdin");
+    }
+
+    let path = Path::new(file_path);
+    match read_file(path) {
+        Err(InputError::NotFound { mut tried }) => {
+            let compressed = gz_path(path);
+            read_file(&compressed).map_err(|e| match e {
+                InputError::NotFound { .. } => {
+                    tried.push(compressed);
+                    InputError::NotFound { tried }
+                }
+                e => e,
+            })
+        }
+        result => result,
+    }
+}
+
+/// Read a day's input from the first of `dirs` that has `dayNN/input.txt`
+/// (or `input.txt.gz`), falling back to the copy aoc-fetch keeps in the user's
+/// cache directory
+pub fn read_day_input(dirs: &[&Path], year: u16, day: u32) -> Result<String, InputError> {
+    let cache = aoc_layout::input_cache_dir(year);
+    let candidates = dirs
+        .iter()
+        .copied()
+        .chain(std::iter::once(cache.as_path()))
+        .map(|dir| dir.join(aoc_layout::day_module(day)).join("input.txt"));
+
+    let mut tried = Vec::new();
+    for path in candidates {
+        match read_input(&path.to_string_lossy()) {
+            Err(InputError::NotFound { tried: missing }) => tried.extend(missing),
+            result => return result,
+        }
+    }
+    Err(InputError::NotFound { tried })
+}
+
+/// `input.txt` -> `input.txt.gz`
+fn gz_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    let source = path.display().to_string();
+    match fs::read(path) {
+        Ok(bytes) => decode(bytes, &source),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::NotFound {
+            tried: vec![path.to_path_buf()],
+        }),
+        Err(error) => Err(InputError::Io { source, error }),
+    }
+}
+
+/// Decompress `bytes` if they are gzip, then check they are UTF-8
+fn decode(bytes: Vec<u8>, source: &str) -> Result<String, InputError> {
+    let bytes = if bytes.starts_with(&GZIP_MAGIC) {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_end(&mut decompressed)
+            .map_err(|error| InputError::Gzip {
+                source: source.to_string(),
+                error,
+            })?;
+        decompressed
+    } else {
+        bytes
+    };
+    String::from_utf8(bytes).map_err(|_| InputError::NotUtf8 {
+        source: source.to_string(),
+    })
 }
 
 /// Parse lines from input string
 pub fn parse_lines(input: &str) -> Vec<&str> {
     input.lines().collect()
 }
+
+/// Error raised when puzzle input is malformed
+/// Line and column are 1-based and point at the offending token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Build an error for `token` found on line `line` (1-based) whose text is `line_text`
+    /// The column is where `token` sits inside `line_text`: exactly if it is a slice of
+    /// the line, otherwise at its first occurrence, and 1 if it does not occur at all
+    pub fn at(line: usize, line_text: &str, token: &str, message: impl Into<String>) -> Self {
+        let column = byte_offset(line_text, token)
+            .map_or(1, |offset| line_text[..offset].chars().count() + 1);
+        Self::new(line, column, token, message)
+    }
+}
+
+/// Byte offset of `token` within `text`
+///
+/// A slice of `text` is located by its address, so a repeated token still
+/// points at the right occurrence; anything else, such as a trimmed copy or
+/// an owned `String`, is searched for by content.
+fn byte_offset(text: &str, token: &str) -> Option<usize> {
+    let range = text.as_bytes().as_ptr_range();
+    let address = token.as_ptr();
+    let is_slice = range.start <= address && address.wrapping_add(token.len()) <= range.end;
+    if is_slice {
+        // Both addresses are in `text`, so the difference is an index into it
+        let offset = address as usize - range.start as usize;
+        return Some(offset).filter(|&offset| text.is_char_boundary(offset));
+    }
+    text.find(token)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}, token '{}': {}",
+            self.line, self.column, self.token, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of puzzle input with its 1-based line number
+///
+/// The parsing helpers take slices of `text` so that errors point at the
+/// right column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// An error pointing at `token`, which should be a slice of this line
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.number, self.text, token, message)
+    }
+
+    /// Parse `token`, reporting `invalid {what}` if it is not a `T`
+    pub fn parse<T: FromStr>(&self, token: &'a str, what: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, format!("invalid {}", what)))
+    }
+
+    /// Parse every whitespace-separated word of `part`
+    pub fn words<T: FromStr>(&self, part: &'a str, what: &str) -> Result<Vec<T>, ParseError> {
+        part.split_whitespace()
+            .map(|word| self.parse(word, what))
+            .collect()
+    }
+
+    /// Parse a `separator`-separated list in `part`; items are trimmed and an
+    /// empty `part` is an empty list
+    pub fn separated<T: FromStr>(
+        &self,
+        part: &'a str,
+        separator: &str,
+        what: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        if part.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        part.split(separator)
+            .map(|item| self.parse(item.trim(), what))
+            .collect()
+    }
+
+    /// Every integer embedded in the line, e.g. `[-6, 0, 3]` from `p=<-6,0,3>`
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        let bytes = self.text.as_bytes();
+        let mut values = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if !(negative || bytes[i].is_ascii_digit()) {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            values.push(self.parse(&self.text[start..i], "number")?);
+        }
+        Ok(values)
+    }
+
+    /// Split the trimmed line around `separator` into a trimmed key and value,
+    /// reporting `expected '{shape}'` if the separator is missing
+    pub fn key_value(
+        &self,
+        separator: &str,
+        shape: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        let line = self.text.trim();
+        let (key, value) = line
+            .split_once(separator)
+            .ok_or_else(|| self.error(line, format!("expected '{}'", shape)))?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// The text between a fixed `prefix` and `suffix` of the trimmed line
+    pub fn field(&self, prefix: &str, suffix: &str) -> Result<&'a str, ParseError> {
+        let line = self.text.trim();
+        line.strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix))
+            .ok_or_else(|| self.error(line, format!("expected '{}...{}'", prefix, suffix)))
+    }
+}
+
+/// Non-blank lines of the input, numbered from 1
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// Parse each non-blank line, stopping at the first error
+pub fn parse_each_line<'a, T>(
+    input: &'a str,
+    parse: impl FnMut(Line<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    numbered_lines(input).map(parse).collect()
+}
+
+/// Groups of lines separated by blank lines
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![Vec::new()];
+    for (i, text) in input.lines().enumerate() {
+        if text.trim().is_empty() {
+            blocks.push(Vec::new());
+        } else if let Some(block) = blocks.last_mut() {
+            block.push(Line::new(i + 1, text));
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+/// The input as rows of characters, ignoring trailing blank lines; rows keep
+/// their leading and trailing spaces and may differ in length
+pub fn char_grid(input: &str) -> Vec<Vec<char>> {
+    let mut rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    while rows
+        .last()
+        .is_some_and(|row| row.iter().all(|c| c.is_whitespace()))
+    {
+        rows.pop();
+    }
+    rows
+}
+
+/// Like [`char_grid`], but every row must be as wide as the first
+pub fn rectangular_grid(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let rows = char_grid(input);
+    let width = rows.first().map_or(0, Vec::len);
+    for (i, row) in rows.iter().enumerate() {
+        if row.len() != width {
+            let text = input.lines().nth(i).unwrap_or("");
+            return Err(ParseError::at(
+                i + 1,
+                text,
+                text,
+                format!("expected {} columns, found {}", width, row.len()),
+            ));
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_column() {
+        let line = "s1,x3/4,q3/4";
+        let token = &line[8..];
+        let err = ParseError::at(1, line, token, "unknown move");
+        assert_eq!(err.column, 9);
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 9, token 'q3/4': unknown move"
+        );
+
+        // Repeated tokens sliced from the line point at the right occurrence
+        let err = ParseError::at(1, line, &line[9..10], "bad");
+        assert_eq!((err.column, err.token.as_str()), (10, "3"));
+
+        // Copies are found by content
+        let copy = String::from(" x3/4 ").trim().to_string();
+        assert_eq!(ParseError::at(1, line, &copy, "bad").column, 4);
+
+        // Tokens that don't occur in the line fall back to column 1
+        let err = ParseError::at(2, line, "other", "bad");
+        assert_eq!(err.column, 1);
+    }
+
+    #[test]
+    fn test_line_lists() {
+        let line = Line::new(3, "5 1  9 5");
+        assert_eq!(line.words::<u32>(line.text, "number"), Ok(vec![5, 1, 9, 5]));
+
+        let line = Line::new(1, "3, 4,x,5");
+        assert_eq!(line.separated::<u8>("", ",", "length"), Ok(vec![]));
+        let err = line.separated::<u8>(line.text, ",", "length").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 6, "x"));
+        assert_eq!(err.message, "invalid length");
+
+        let line = Line::new(2, "p=<-6,0,3>, v=< 3,-1,0>");
+        assert_eq!(line.ints::<i64>(), Ok(vec![-6, 0, 3, 3, -1, 0]));
+        assert_eq!(Line::new(1, "a-b").ints::<i32>(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_line_patterns() {
+        let line = Line::new(4, "  6 <-> 4, 5");
+        assert_eq!(line.key_value("<->", "id <-> ids"), Ok(("6", "4, 5")));
+        let err = line.key_value(":", "depth: range").unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (3, "expected 'depth: range'")
+        );
+
+        let line = Line::new(1, "Begin in state A.");
+        assert_eq!(line.field("Begin in state ", "."), Ok("A"));
+        assert!(line.field("In state ", ":").is_err());
+    }
+
+    #[test]
+    fn test_lines_and_blocks() {
+        let input = "a\n\n b\nc\n\n\nd\n";
+        let numbers: Vec<usize> = numbered_lines(input).map(|line| line.number).collect();
+        assert_eq!(numbers, [1, 3, 4, 7]);
+
+        let blocks = blocks(input);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1], [Line::new(3, " b"), Line::new(4, "c")]);
+
+        let parsed = parse_each_line("1\n\n2\n", |line| line.parse::<u8>(line.text, "digit"));
+        assert_eq!(parsed, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_grids() {
+        assert_eq!(char_grid(" |\n+-\n\n"), [vec![' ', '|'], vec!['+', '-']]);
+        assert_eq!(rectangular_grid("..#\n#..\n").unwrap().len(), 2);
+        let err = rectangular_grid("..#\n#.\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "expected 3 columns, found 2")
+        );
+    }
+
+    #[test]
+    fn test_read_input() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let dir = std::env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        let day_dir = dir.join("day03");
+        fs::create_dir_all(&day_dir).unwrap();
+        let plain = day_dir.join("input.txt");
+        fs::write(&plain, "1\n2\n").unwrap();
+        assert_eq!(read_input(&plain.to_string_lossy()).unwrap(), "1\n2\n");
+
+        // Only the compressed file exists
+        fs::remove_file(&plain).unwrap();
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"3\n").unwrap();
+        fs::write(gz_path(&plain), encoder.finish().unwrap()).unwrap();
+        assert_eq!(read_input(&plain.to_string_lossy()).unwrap(), "3\n");
+        assert_eq!(read_day_input(&[&dir], 2017, 3).unwrap(), "3\n");
+
+        // Later directories fill in days missing from earlier ones
+        let empty = dir.join("empty");
+        assert_eq!(read_day_input(&[&empty, &dir], 2017, 3).unwrap(), "3\n");
+
+        let missing = read_day_input(&[&dir], 2017, 26).unwrap_err();
+        match &missing {
+            InputError::NotFound { tried } => assert_eq!(tried.len(), 4),
+            e => panic!("unexpected error {}", e),
+        }
+        assert!(missing.to_string().starts_with("no input found (tried "));
+
+        fs::write(&plain, [0x1f, 0x8b, 0x00]).unwrap();
+        assert!(matches!(
+            read_input(&plain.to_string_lossy()),
+            Err(InputError::Gzip { .. })
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,7 +1,10 @@
 // Utility modules for Advent of Code solutions
 
+pub mod geom;
 pub mod graph;
 pub mod grid;
 pub mod hash;
+pub mod hex;
 pub mod input;
 pub mod math;
+pub mod tree;
@@ -0,0 +1,400 @@
+// Rooted trees stored in an arena, built from `node value -> children` listings
+
+use crate::utils::input::{self, Line, ParseError};
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::hash::Hash;
+
+/// Index of a node in its tree's arena
+pub type NodeId = usize;
+
+#[derive(Debug, Clone)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// Why a listing of nodes does not form a single rooted tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeError<K> {
+    /// The same key is listed twice
+    Duplicate(K),
+    /// `parent` lists a child that is never defined
+    UnknownChild { parent: K, child: K },
+    /// `child` is listed under a second parent, `parent`
+    SecondParent { child: K, parent: K },
+    /// There are no nodes at all
+    Empty,
+    /// Some nodes cannot be reached from the root because they hold each other
+    Cycle,
+    /// `second` has no parent either, but `first` was already the root
+    SecondRoot { first: K, second: K },
+}
+
+impl<K: fmt::Display> fmt::Display for TreeError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeError::Duplicate(key) => write!(f, "'{}' is listed twice", key),
+            TreeError::UnknownChild { parent, child } => {
+                write!(f, "'{}' holds unknown node '{}'", parent, child)
+            }
+            TreeError::SecondParent { child, parent } => {
+                write!(f, "'{}' is also held by '{}'", child, parent)
+            }
+            TreeError::Empty => write!(f, "no nodes"),
+            TreeError::Cycle => write!(f, "some nodes form a cycle"),
+            TreeError::SecondRoot { first, second } => {
+                write!(f, "'{}' and '{}' are both roots", first, second)
+            }
+        }
+    }
+}
+
+/// A rooted tree whose nodes carry a unique key and a value
+#[derive(Debug, Clone)]
+pub struct Tree<K, V> {
+    nodes: Vec<Node<K, V>>,
+    ids: HashMap<K, NodeId>,
+    root: NodeId,
+}
+
+impl<K: Clone + Eq + Hash, V> Tree<K, V> {
+    /// Build a tree from `(key, value, children)` entries in any order; the
+    /// root is the one node that no entry lists as a child
+    pub fn from_entries(
+        entries: impl IntoIterator<Item = (K, V, Vec<K>)>,
+    ) -> Result<Self, TreeError<K>> {
+        let entries: Vec<(K, V, Vec<K>)> = entries.into_iter().collect();
+        let mut ids = HashMap::with_capacity(entries.len());
+        for (id, (key, _, _)) in entries.iter().enumerate() {
+            if ids.insert(key.clone(), id).is_some() {
+                return Err(TreeError::Duplicate(key.clone()));
+            }
+        }
+
+        let mut nodes: Vec<Node<K, V>> = Vec::with_capacity(entries.len());
+        let mut child_keys = Vec::with_capacity(entries.len());
+        for (key, value, children) in entries {
+            nodes.push(Node {
+                key,
+                value,
+                parent: None,
+                children: Vec::with_capacity(children.len()),
+            });
+            child_keys.push(children);
+        }
+
+        for (id, children) in child_keys.into_iter().enumerate() {
+            for child in children {
+                let Some(&child_id) = ids.get(&child) else {
+                    return Err(TreeError::UnknownChild {
+                        parent: nodes[id].key.clone(),
+                        child,
+                    });
+                };
+                if nodes[child_id].parent.is_some() {
+                    return Err(TreeError::SecondParent {
+                        child,
+                        parent: nodes[id].key.clone(),
+                    });
+                }
+                nodes[child_id].parent = Some(id);
+                nodes[id].children.push(child_id);
+            }
+        }
+
+        if nodes.is_empty() {
+            return Err(TreeError::Empty);
+        }
+        let mut roots = (0..nodes.len()).filter(|&id| nodes[id].parent.is_none());
+        let root = roots.next().ok_or(TreeError::Cycle)?;
+        if let Some(second) = roots.next() {
+            return Err(TreeError::SecondRoot {
+                first: nodes[root].key.clone(),
+                second: nodes[second].key.clone(),
+            });
+        }
+        // A single root with a cycle elsewhere leaves nodes unreachable
+        let tree = Self { nodes, ids, root };
+        if tree.subtree(root).len() != tree.len() {
+            return Err(TreeError::Cycle);
+        }
+        Ok(tree)
+    }
+
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The node with the given key
+    pub fn get(&self, key: &K) -> Option<NodeId> {
+        self.ids.get(key).copied()
+    }
+
+    pub fn key(&self, id: NodeId) -> &K {
+        &self.nodes[id].key
+    }
+
+    pub fn value(&self, id: NodeId) -> &V {
+        &self.nodes[id].value
+    }
+
+    pub fn value_mut(&mut self, id: NodeId) -> &mut V {
+        &mut self.nodes[id].value
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id].parent
+    }
+
+    /// Children in the order they were listed
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id].children
+    }
+
+    /// Number of edges between `id` and the root
+    pub fn depth(&self, id: NodeId) -> usize {
+        self.ancestors(id).count()
+    }
+
+    /// Parent, grandparent and so on up to the root
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self.parent(id), |&id| self.parent(id))
+    }
+
+    /// Whether `ancestor` is `id` itself or lies on its path to the root
+    pub fn is_ancestor(&self, ancestor: NodeId, id: NodeId) -> bool {
+        ancestor == id || self.ancestors(id).any(|a| a == ancestor)
+    }
+
+    /// `id` and all its descendants, parents before children
+    pub fn subtree(&self, id: NodeId) -> Vec<NodeId> {
+        self.pre_order_from(id)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Every node with its depth, parents before children and children in order
+    pub fn pre_order(&self) -> Vec<(NodeId, usize)> {
+        self.pre_order_from(self.root)
+    }
+
+    fn pre_order_from(&self, start: NodeId) -> Vec<(NodeId, usize)> {
+        let mut order = Vec::new();
+        let mut stack = vec![(start, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            order.push((id, depth));
+            for &child in self.children(id).iter().rev() {
+                stack.push((child, depth + 1));
+            }
+        }
+        order
+    }
+
+    /// Every node, children before parents
+    pub fn post_order(&self) -> Vec<NodeId> {
+        let mut result = Vec::with_capacity(self.len());
+        let mut stack = vec![(self.root, false)];
+        while let Some((id, expanded)) = stack.pop() {
+            if expanded {
+                result.push(id);
+            } else {
+                stack.push((id, true));
+                for &child in self.children(id).iter().rev() {
+                    stack.push((child, false));
+                }
+            }
+        }
+        result
+    }
+
+    /// Compute a value for every node from the node and its children's values,
+    /// e.g. subtree sizes or weights; the result is indexed by [`NodeId`]
+    pub fn aggregate<A>(&self, mut f: impl FnMut(NodeId, &[&A]) -> A) -> Vec<A> {
+        let mut results: Vec<Option<A>> = (0..self.len()).map(|_| None).collect();
+        for id in self.post_order() {
+            let children: Vec<&A> = self
+                .children(id)
+                .iter()
+                .filter_map(|&child| results[child].as_ref())
+                .collect();
+            let value = f(id, &children);
+            results[id] = Some(value);
+        }
+        results.into_iter().flatten().collect()
+    }
+
+    /// Draw the tree with one node per line, using `label` for the text
+    ///
+    /// ```text
+    /// root
+    /// |-- a
+    /// |   `-- b
+    /// `-- c
+    /// ```
+    pub fn render_ascii(&self, label: impl Fn(NodeId) -> String) -> String {
+        let mut out = String::new();
+        // Each entry carries the prefix for the node's own line and for its children
+        let mut stack = vec![(self.root, String::new(), String::new())];
+        while let Some((id, prefix, child_prefix)) = stack.pop() {
+            let _ = writeln!(out, "{}{}", prefix, label(id));
+            let children = self.children(id);
+            for (i, &child) in children.iter().enumerate().rev() {
+                let last = i + 1 == children.len();
+                let (branch, indent) = if last {
+                    ("`-- ", "    ")
+                } else {
+                    ("|-- ", "|   ")
+                };
+                stack.push((
+                    child,
+                    format!("{}{}", child_prefix, branch),
+                    format!("{}{}", child_prefix, indent),
+                ));
+            }
+        }
+        out
+    }
+
+    /// A Graphviz DOT digraph of the tree, using `label` for node labels
+    pub fn render_dot(&self, label: impl Fn(NodeId) -> String) -> String {
+        let mut out = String::from("digraph tree {\n");
+        for (id, _) in self.pre_order() {
+            let text = label(id).replace('\\', "\\\\").replace('"', "\\\"");
+            let _ = writeln!(out, "  n{} [label=\"{}\"];", id, text);
+            for child in self.children(id) {
+                let _ = writeln!(out, "  n{} -> n{};", id, child);
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+impl<V> Tree<String, V> {
+    /// Parse one node per non-blank line with `parse_line`, which returns the
+    /// node's key, value and child keys as slices of the line so that
+    /// structural errors can point at them
+    pub fn parse<'a>(
+        input: &'a str,
+        mut parse_line: impl FnMut(Line<'a>) -> Result<(&'a str, V, Vec<&'a str>), ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        let mut entries = Vec::new();
+        for line in input::numbered_lines(input) {
+            let (key, value, children) = parse_line(line)?;
+            entries.push((
+                key.to_string(),
+                value,
+                children.iter().map(|c| c.to_string()).collect(),
+            ));
+            lines.push((line, key, children));
+        }
+
+        Self::from_entries(entries).map_err(|e| {
+            let message = e.to_string();
+            // The line that lists `key` and, if given, the slice naming `child` on it
+            let locate = |key: &str, child: Option<&str>| {
+                lines
+                    .iter()
+                    .rev()
+                    .find(|(_, k, _)| *k == key)
+                    .map(|(line, k, children)| {
+                        let token = child
+                            .and_then(|c| children.iter().rev().find(|&&s| s == c))
+                            .unwrap_or(k);
+                        line.error(token, message.clone())
+                    })
+            };
+            match &e {
+                TreeError::Duplicate(key) => locate(key, None),
+                TreeError::UnknownChild { parent, child } => locate(parent, Some(child)),
+                TreeError::SecondParent { child, parent } => locate(parent, Some(child)),
+                TreeError::SecondRoot { second, .. } => locate(second, None),
+                TreeError::Empty | TreeError::Cycle => None,
+            }
+            .unwrap_or_else(|| ParseError::new(1, 1, "", message.clone()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `name value -> children` lines
+    fn parse(input: &str) -> Result<Tree<String, u32>, ParseError> {
+        Tree::parse(input, |line| {
+            let (node, children) = line.text.split_once(" -> ").unwrap_or((line.text, ""));
+            let (key, value) = node.split_once(' ').unwrap_or((node, "0"));
+            let children = children.split(", ").filter(|c| !c.is_empty()).collect();
+            Ok((key, line.parse(value, "value")?, children))
+        })
+    }
+
+    const EXAMPLE: &str = "b 2 -> d\na 1 -> b, c\nc 3\nd 4\n";
+
+    #[test]
+    fn test_structure() {
+        let tree = parse(EXAMPLE).unwrap();
+        let [a, b, c, d] = ["a", "b", "c", "d"].map(|k| tree.get(&k.to_string()).unwrap());
+        assert_eq!(tree.root(), a);
+        assert_eq!(tree.children(a), [b, c]);
+        assert_eq!(tree.parent(d), Some(b));
+        assert_eq!(tree.depth(d), 2);
+        assert!(tree.is_ancestor(a, d));
+        assert!(!tree.is_ancestor(c, d));
+        assert_eq!(tree.subtree(b), [b, d]);
+        assert_eq!(tree.post_order(), [d, b, c, a]);
+
+        let totals =
+            tree.aggregate(|id, children| tree.value(id) + children.iter().copied().sum::<u32>());
+        assert_eq!((totals[a], totals[b], totals[c]), (10, 6, 3));
+    }
+
+    #[test]
+    fn test_render() {
+        let tree = parse(EXAMPLE).unwrap();
+        let label = |id| format!("{} ({})", tree.key(id), tree.value(id));
+        assert_eq!(
+            tree.render_ascii(label),
+            "a (1)\n|-- b (2)\n|   `-- d (4)\n`-- c (3)\n"
+        );
+        let dot = tree.render_dot(|id| tree.key(id).clone());
+        assert!(dot.starts_with("digraph tree {\n"));
+        assert!(dot.contains("[label=\"d\"]"));
+        assert_eq!(dot.matches(" -> ").count(), 3);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = parse("a 1 -> b, x\nb 2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 11, "x"));
+        let err = parse("a 1 -> b\nc 1 -> b\nb 2\n").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (2, "b"));
+        let err = parse("a 1\nb 2\n").unwrap_err();
+        assert_eq!(err.message, "'a' and 'b' are both roots");
+        let err = parse("r 0 -> c\nc 0\na 1 -> b\nb 1 -> a\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (1, "some nodes form a cycle")
+        );
+        assert_eq!(parse("\n").unwrap_err().message, "no nodes");
+        assert_eq!(
+            Tree::from_entries([(1, (), vec![]), (1, (), vec![])]).unwrap_err(),
+            TreeError::Duplicate(1)
+        );
+    }
+}
@@ -14,7 +14,7 @@ struct Args {
     #[arg(help = "Day number (1-25)")]
     day: u8,
 
-    #[arg(short, long, default_value = "2017", help = "Year")]
+    #[arg(short, long, default_value_t = aoc_layout::DEFAULT_YEAR, help = "Year")]
     year: u16,
 
     #[arg(
@@ -50,7 +50,7 @@ fn main() -> Result<()> {
 
     let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
 
-    let day_dir = format!("src/solutions/day{:02}", args.day);
+    let day_dir = aoc_layout::day_dir(&aoc_layout::repo_root(), args.year, args.day.into());
     fs::create_dir_all(&day_dir)?;
 
     let part_display = if args.part == "complete" {
@@ -76,7 +76,16 @@ fn main() -> Result<()> {
 
     fetch_input(&client, &session_cookie, args.year, args.day, &day_dir)?;
 
-    println!("Successfully fetched puzzle and input to {}/", day_dir);
+    println!(
+        "Successfully fetched puzzle and input to {}/",
+        day_dir.display()
+    );
+    if !day_dir.join("mod.rs").exists() {
+        println!(
+            "Run `cargo run -p aoc-new -- {} --year {}` to scaffold the solution",
+            args.day, args.year
+        );
+    }
     Ok(())
 }
 
@@ -85,7 +94,7 @@ fn fetch_puzzle(
     session: &str,
     year: u16,
     day: u8,
-    dir: &str,
+    dir: &Path,
     part: &str,
 ) -> Result<()> {
     let url = format!("https://adventofcode.com/{}/day/{}", year, day);
@@ -125,7 +134,7 @@ fn fetch_puzzle(
     final_content.push_str("\n\n");
     final_content.push_str(&puzzle_text);
 
-    let puzzle_path = Path::new(dir).join("puzzle.txt");
+    let puzzle_path = dir.join("puzzle.txt");
     // Ensure file ends with a newline
     if !final_content.ends_with('\n') {
         final_content.push('\n');
@@ -248,7 +257,7 @@ fn html_to_text(html: &str) -> String {
     text.trim().to_string()
 }
 
-fn fetch_input(client: &Client, session: &str, year: u16, day: u8, dir: &str) -> Result<()> {
+fn fetch_input(client: &Client, session: &str, year: u16, day: u8, dir: &Path) -> Result<()> {
     let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
 
     let response = client
@@ -277,8 +286,13 @@ fn fetch_input(client: &Client, session: &str, year: u16, day: u8, dir: &str) ->
         return Err(anyhow!("Rate limited. Please wait before trying again"));
     }
 
-    let input_path = Path::new(dir).join("input.txt");
-    fs::write(input_path, input_content)?;
+    let input_path = dir.join("input.txt");
+    fs::write(input_path, &input_content)?;
+
+    // Keep a copy where a runner built elsewhere can find it at run time
+    let cache_dir = aoc_layout::input_cache_dir(year).join(aoc_layout::day_module(day.into()));
+    fs::create_dir_all(&cache_dir)?;
+    fs::write(cache_dir.join("input.txt"), &input_content)?;
 
     println!("  ✓ Input data saved");
     Ok(())
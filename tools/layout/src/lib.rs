@@ -0,0 +1,157 @@
+// Year-scoped repository layout shared by the runner, aoc-fetch, aoc-submit and aoc-new
+//
+// Solutions live in `src/solutions/yYYYY/dayNN/`, next to the day's `input.txt`
+// and `puzzle.txt`, with one `answers.json` manifest per year.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Directory holding all years, relative to the repository root
+pub const SOLUTIONS_DIR: &str = "src/solutions";
+
+/// Year used when none is given on the command line
+pub const DEFAULT_YEAR: u16 = 2017;
+
+/// Module name for a year, e.g. `y2017`
+pub fn year_module(year: u16) -> String {
+    format!("y{}", year)
+}
+
+/// Module name for a day, e.g. `day07`
+pub fn day_module(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+/// Root of this repository, resolved when the layout crate is built so that
+/// every tool agrees on it whatever directory it is run from
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("layout crate lives in tools/layout")
+        .to_path_buf()
+}
+
+/// Per-user cache shared by the runner and the tools, resolved at run time:
+/// `$AOC_CACHE_DIR`, else `$XDG_CACHE_HOME/advent-of-code`, else
+/// `~/.cache/advent-of-code`, else the system temp directory
+pub fn cache_dir() -> PathBuf {
+    cache_dir_from(|name| std::env::var_os(name))
+}
+
+fn cache_dir_from(var: impl Fn(&str) -> Option<OsString>) -> PathBuf {
+    var("AOC_CACHE_DIR")
+        .map(PathBuf::from)
+        .or_else(|| var("XDG_CACHE_HOME").map(|dir| PathBuf::from(dir).join("advent-of-code")))
+        .or_else(|| {
+            var("HOME").map(|home| PathBuf::from(home).join(".cache").join("advent-of-code"))
+        })
+        .unwrap_or_else(|| std::env::temp_dir().join("advent-of-code"))
+}
+
+/// Where aoc-fetch keeps a copy of each input, `<cache>/inputs/yYYYY`, laid
+/// out like a runner `--input-dir` with one `dayNN/input.txt` per day
+pub fn input_cache_dir(year: u16) -> PathBuf {
+    cache_dir().join("inputs").join(year_module(year))
+}
+
+/// `src/solutions/yYYYY`
+pub fn year_dir(root: &Path, year: u16) -> PathBuf {
+    root.join(SOLUTIONS_DIR).join(year_module(year))
+}
+
+/// `src/solutions/yYYYY/dayNN`
+pub fn day_dir(root: &Path, year: u16, day: u32) -> PathBuf {
+    year_dir(root, year).join(day_module(day))
+}
+
+/// `src/solutions/yYYYY/dayNN/input.txt`
+pub fn input_path(root: &Path, year: u16, day: u32) -> PathBuf {
+    day_dir(root, year, day).join("input.txt")
+}
+
+/// `src/solutions/yYYYY/dayNN/puzzle.txt`
+pub fn puzzle_path(root: &Path, year: u16, day: u32) -> PathBuf {
+    day_dir(root, year, day).join("puzzle.txt")
+}
+
+/// `src/solutions/yYYYY/dayNN/examples.txt`
+pub fn examples_path(root: &Path, year: u16, day: u32) -> PathBuf {
+    day_dir(root, year, day).join("examples.txt")
+}
+
+/// `src/solutions/yYYYY/answers.json`
+pub fn answers_path(root: &Path, year: u16) -> PathBuf {
+    year_dir(root, year).join("answers.json")
+}
+
+/// Same as [`day_dir`] but relative to the repository root and with forward
+/// slashes, for links in the README
+pub fn day_link(year: u16, day: u32) -> String {
+    format!(
+        "{}/{}/{}",
+        SOLUTIONS_DIR,
+        year_module(year),
+        day_module(day)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let root = Path::new("repo");
+        assert_eq!(
+            input_path(root, 2017, 7),
+            Path::new("repo/src/solutions/y2017/day07/input.txt")
+        );
+        assert_eq!(
+            examples_path(root, 2017, 7),
+            Path::new("repo/src/solutions/y2017/day07/examples.txt")
+        );
+        assert_eq!(
+            answers_path(root, 2016),
+            Path::new("repo/src/solutions/y2016/answers.json")
+        );
+        assert_eq!(day_link(2017, 25), "src/solutions/y2017/day25");
+    }
+
+    #[test]
+    fn test_repo_root() {
+        let root = repo_root();
+        assert!(root.join("Cargo.toml").is_file());
+        assert!(root.join("tools/layout/Cargo.toml").is_file());
+    }
+
+    #[test]
+    fn test_cache_dir() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| OsString::from(value))
+            }
+        };
+        const ALL: [(&str, &str); 3] = [
+            ("AOC_CACHE_DIR", "/aoc"),
+            ("XDG_CACHE_HOME", "/xdg"),
+            ("HOME", "/home/elf"),
+        ];
+        assert_eq!(cache_dir_from(env(&ALL)), Path::new("/aoc"));
+        assert_eq!(
+            cache_dir_from(env(&ALL[1..])),
+            Path::new("/xdg/advent-of-code")
+        );
+        assert_eq!(
+            cache_dir_from(env(&ALL[2..])),
+            Path::new("/home/elf/.cache/advent-of-code")
+        );
+        assert_eq!(
+            cache_dir_from(env(&[])),
+            std::env::temp_dir().join("advent-of-code")
+        );
+        assert!(input_cache_dir(2017).ends_with("inputs/y2017"));
+    }
+}
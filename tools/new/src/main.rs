@@ -0,0 +1,451 @@
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs");
+
+const TABLE_HEADER: [&str; 2] = [
+    "| Day | Puzzle | Solution | Input | Text |",
+    "|-----|--------|----------|-------|------|",
+];
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Scaffold a new day's solution module", long_about = None)]
+struct Args {
+    #[arg(help = "Day number (1-25)")]
+    day: u32,
+
+    #[arg(short, long, default_value_t = aoc_layout::DEFAULT_YEAR, help = "Year")]
+    year: u16,
+
+    #[arg(
+        short,
+        long,
+        help = "Puzzle title (default: read from the day's puzzle.txt)"
+    )]
+    title: Option<String>,
+
+    #[arg(
+        long,
+        default_value_os_t = aoc_layout::repo_root(),
+        help = "Repository root (default: the checkout this tool was built from)"
+    )]
+    root: PathBuf,
+
+    #[arg(long, help = "Overwrite an existing mod.rs with the template")]
+    force: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    if args.day < 1 || args.day > 25 {
+        return Err(anyhow!("Day must be between 1 and 25"));
+    }
+
+    let (root, year, day) = (args.root.as_path(), args.year, args.day);
+    let day_dir = aoc_layout::day_dir(root, year, day);
+    fs::create_dir_all(&day_dir)?;
+
+    let title = match args.title {
+        Some(title) => title,
+        None => read_title(&aoc_layout::puzzle_path(root, year, day))?,
+    };
+
+    let module_path = day_dir.join("mod.rs");
+    if module_path.exists() && !args.force {
+        println!("Keeping existing {}", module_path.display());
+    } else {
+        fs::write(&module_path, render(DAY_TEMPLATE, year, day, &title))?;
+        println!("Created {}", module_path.display());
+    }
+
+    // Modules embed their input and answers, so those files must exist to compile
+    create_if_missing(&aoc_layout::input_path(root, year, day), "")?;
+    create_if_missing(&aoc_layout::answers_path(root, year), "{}\n")?;
+
+    let year_module = aoc_layout::year_dir(root, year).join("mod.rs");
+    let mut changed = if year_module.exists() {
+        update_file(&year_module, |text| register_day(text, day))?
+    } else {
+        fs::write(&year_module, render(YEAR_TEMPLATE, year, day, &title))?;
+        println!("Created {}", year_module.display());
+        true
+    };
+    changed |= update_file(
+        &root.join(aoc_layout::SOLUTIONS_DIR).join("mod.rs"),
+        |text| register_year(text, year),
+    )?;
+    update_file(&root.join("README.md"), |text| {
+        update_readme(text, year, day, &title)
+    })?;
+
+    if changed {
+        format_sources(root);
+    }
+    Ok(())
+}
+
+/// Extract the title from the "--- Day N: Title ---" line written by aoc-fetch
+fn read_title(puzzle_path: &Path) -> Result<String> {
+    let text = fs::read_to_string(puzzle_path).with_context(|| {
+        format!(
+            "No --title given and {} could not be read",
+            puzzle_path.display()
+        )
+    })?;
+    parse_title(&text).ok_or_else(|| {
+        anyhow!(
+            "No --title given and {} has no title line",
+            puzzle_path.display()
+        )
+    })
+}
+
+fn parse_title(puzzle: &str) -> Option<String> {
+    puzzle.lines().find_map(|line| {
+        let inner = line.trim().strip_prefix("--- Day ")?.strip_suffix(" ---")?;
+        let (_, title) = inner.split_once(": ")?;
+        Some(title.to_string())
+    })
+}
+
+fn render(template: &str, year: u16, day: u32, title: &str) -> String {
+    template
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DAY_MODULE}}", &aoc_layout::day_module(day))
+        .replace("{{DAY_PADDED}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{TITLE}}", &title.replace('"', "\\\""))
+}
+
+fn create_if_missing(path: &Path, contents: &str) -> Result<()> {
+    if !path.exists() {
+        fs::write(path, contents)?;
+        println!("Created {}", path.display());
+    }
+    Ok(())
+}
+
+/// Apply an edit to a file, writing it back only when something changed
+fn update_file(path: &Path, edit: impl Fn(&str) -> Result<Option<String>>) -> Result<bool> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    match edit(&text)? {
+        Some(updated) => {
+            fs::write(path, updated)?;
+            println!("Updated {}", path.display());
+            Ok(true)
+        }
+        None => {
+            println!("{} is already up to date", path.display());
+            Ok(false)
+        }
+    }
+}
+
+/// Bring edited registries back to rustfmt style; a missing rustfmt is not fatal
+fn format_sources(root: &Path) {
+    let registry = root.join(aoc_layout::SOLUTIONS_DIR).join("mod.rs");
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .arg(&registry)
+        .status();
+    if !status.is_ok_and(|s| s.success()) {
+        println!("Could not run rustfmt, run `cargo fmt` before committing");
+    }
+}
+
+/// Add `pub mod dayNN;` and the registry entry to the year's `mod.rs`
+fn register_day(text: &str, day: u32) -> Result<Option<String>> {
+    let with_mod = upsert_line(
+        text,
+        |_, line| number(line.strip_prefix("pub mod day")?.strip_suffix(';')?, 2),
+        day,
+        &format!("pub mod {};", aoc_layout::day_module(day)),
+    )
+    .context("no `pub mod dayNN;` declarations found")?;
+    let current = with_mod.as_deref().unwrap_or(text);
+
+    let with_entry = upsert_list_entry(
+        current,
+        "SOLUTIONS",
+        |entry| number(entry.strip_prefix("&day")?.split("::").next()?, 2),
+        day,
+        &format!("&{}::Day{:02}", aoc_layout::day_module(day), day),
+    )
+    .context("no SOLUTIONS registry found")?;
+
+    Ok(with_entry.or(with_mod))
+}
+
+/// Add `pub mod yYYYY;` and the year's entry to `src/solutions/mod.rs`
+fn register_year(text: &str, year: u16) -> Result<Option<String>> {
+    let year = u32::from(year);
+    let with_mod = upsert_line(
+        text,
+        |_, line| number(line.strip_prefix("pub mod y")?.strip_suffix(';')?, 4),
+        year,
+        &format!("pub mod y{};", year),
+    )
+    .context("no `pub mod yYYYY;` declarations found")?;
+    let current = with_mod.as_deref().unwrap_or(text);
+
+    let with_entry = upsert_list_entry(
+        current,
+        "YEARS",
+        |entry| number(entry.strip_prefix("&y")?.split("::").next()?, 4),
+        year,
+        &format!("&y{}::YEAR", year),
+    )
+    .context("no YEARS registry found")?;
+
+    Ok(with_entry.or(with_mod))
+}
+
+/// Add or refresh the day's row in the year's section of the README progress table
+fn update_readme(text: &str, year: u16, day: u32, title: &str) -> Result<Option<String>> {
+    let link = aoc_layout::day_link(year, day);
+    let row = format!(
+        "| {:02}  | [Day {:02}: {}](https://adventofcode.com/{}/day/{}) \
+         | [Day {:02} solution]({}/mod.rs) \
+         | [Day {:02} input]({}/input.txt) \
+         | [Day {:02} puzzle]({}/puzzle.txt) |",
+        day, day, title, year, day, day, link, day, link, day, link
+    );
+
+    let lines: Vec<&str> = text.lines().collect();
+    let heading = format!("### {}", year);
+    let Some(start) = lines.iter().position(|line| line.trim() == heading) else {
+        return add_readme_section(text, &heading, &row).map(Some);
+    };
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| line.starts_with('#'))
+        .map_or(lines.len(), |i| start + 1 + i);
+
+    upsert_line(
+        text,
+        |line_no, line| {
+            // Only rows inside this year's section count as entries
+            if (start..end).contains(&line_no) {
+                number(line.strip_prefix("| ")?.split_whitespace().next()?, 2)
+            } else {
+                None
+            }
+        },
+        day,
+        &row,
+    )
+    .with_context(|| format!("no table found under {} in README.md", heading))
+}
+
+/// Append a new year's table at the end of the "## Progress" section
+fn add_readme_section(text: &str, heading: &str, row: &str) -> Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let progress = lines
+        .iter()
+        .position(|line| line.trim() == "## Progress")
+        .context("no ## Progress section found in README.md")?;
+    let mut end = lines[progress + 1..]
+        .iter()
+        .position(|line| line.starts_with("## "))
+        .map_or(lines.len(), |i| progress + 1 + i);
+    while end > progress + 1 && lines[end - 1].trim().is_empty() {
+        end -= 1;
+    }
+
+    let mut section = vec!["", heading, ""];
+    section.extend(TABLE_HEADER);
+    section.push(row);
+    if lines.get(end).is_some_and(|line| !line.trim().is_empty()) {
+        section.push("");
+    }
+    lines.splice(end..end, section);
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    Ok(updated)
+}
+
+/// Parse a zero-padded number with exactly `digits` digits
+fn number(s: &str, digits: usize) -> Option<u32> {
+    if s.len() == digits && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Insert `new_line` among the lines for which `entry_key` (given the line
+/// index and text) returns a key,
+/// keeping them sorted, or replace the existing line for the same key
+///
+/// Returns `Some(None)` when the line is already present unchanged and
+/// `None` when the text has no entries to anchor the insertion.
+fn upsert_line(
+    text: &str,
+    entry_key: impl Fn(usize, &str) -> Option<u32>,
+    key: u32,
+    new_line: &str,
+) -> Option<Option<String>> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| entry_key(i, line).map(|k| (i, k)))
+        .collect();
+    let &(last_index, _) = entries.last()?;
+
+    match entries.iter().find(|&&(_, k)| k >= key) {
+        Some(&(index, k)) if k == key => {
+            if lines[index] == new_line {
+                return Some(None);
+            }
+            lines[index] = new_line;
+        }
+        Some(&(index, _)) => lines.insert(index, new_line),
+        None => lines.insert(last_index + 1, new_line),
+    }
+
+    let mut updated = lines.join("\n");
+    if text.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(Some(updated))
+}
+
+/// Make the `pub static NAME ... = &[...];` list contain `entry` for `key`,
+/// rewriting it one entry per line and sorted by key
+///
+/// Returns `Some(None)` when the entry is already present and `None` when the
+/// list cannot be found or holds entries it does not understand.
+fn upsert_list_entry(
+    text: &str,
+    name: &str,
+    entry_key: impl Fn(&str) -> Option<u32>,
+    key: u32,
+    entry: &str,
+) -> Option<Option<String>> {
+    let start = text.find(&format!("pub static {}:", name))?;
+    let open = start + text[start..].find("= &[")? + 4;
+    let close = open + text[open..].find("];")?;
+
+    let mut entries: Vec<(u32, String)> = text[open..close]
+        .split(',')
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .map(|e| Some((entry_key(e)?, e.to_string())))
+        .collect::<Option<_>>()?;
+    if entries.iter().any(|(k, e)| *k == key && e == entry) {
+        return Some(None);
+    }
+    entries.retain(|(k, _)| *k != key);
+    entries.push((key, entry.to_string()));
+    entries.sort();
+
+    let body: String = entries
+        .iter()
+        .map(|(_, e)| format!("\n    {},", e))
+        .collect();
+    Some(Some(format!(
+        "{}{}\n{}",
+        &text[..open],
+        body,
+        &text[close..]
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_RS: &str = "use crate::solutions::Solution;\n\npub mod day01;\npub mod day03;\n\npub static SOLUTIONS: &[&dyn Solution] = &[&day01::Day01, &day03::Day03];\n";
+
+    #[test]
+    fn test_register_day() {
+        let updated = register_day(YEAR_RS, 2).unwrap().unwrap();
+        assert_eq!(
+            updated,
+            "use crate::solutions::Solution;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\npub static SOLUTIONS: &[&dyn Solution] = &[\n    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n];\n"
+        );
+        assert_eq!(register_day(&updated, 2).unwrap(), None);
+
+        let appended = register_day(YEAR_RS, 4).unwrap().unwrap();
+        assert!(appended.contains("pub mod day03;\npub mod day04;\n\n"));
+        assert!(appended.contains("    &day03::Day03,\n    &day04::Day04,\n];"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let registry = "pub mod y2017;\n\npub static YEARS: &[&Year] = &[&y2017::YEAR];\n";
+        let updated = register_year(registry, 2016).unwrap().unwrap();
+        assert_eq!(
+            updated,
+            "pub mod y2016;\npub mod y2017;\n\npub static YEARS: &[&Year] = &[\n    &y2016::YEAR,\n    &y2017::YEAR,\n];\n"
+        );
+        assert_eq!(register_year(&updated, 2017).unwrap(), None);
+        assert_eq!(register_year(registry, 2017).unwrap(), None);
+    }
+
+    #[test]
+    fn test_update_readme() {
+        let readme = "## Progress\n\n### 2017\n\n| Day | Puzzle |\n|-----|--------|\n| 01  | one |\n\n## Notes\n";
+        let updated = update_readme(readme, 2017, 2, "I Heard \"You\"")
+            .unwrap()
+            .unwrap();
+        let lines: Vec<&str> = updated.lines().collect();
+        assert_eq!(lines[6], "| 01  | one |");
+        assert!(lines[7].starts_with(
+            "| 02  | [Day 02: I Heard \"You\"](https://adventofcode.com/2017/day/2) | [Day 02 solution](src/solutions/y2017/day02/mod.rs)"
+        ));
+        assert_eq!(lines[8], "");
+        assert_eq!(
+            update_readme(&updated, 2017, 2, "I Heard \"You\"").unwrap(),
+            None
+        );
+        assert!(update_readme("no table\n", 2017, 2, "x").is_err());
+    }
+
+    #[test]
+    fn test_update_readme_new_year() {
+        let readme = "## Progress\n\n### 2017\n\n| Day | Puzzle |\n|-----|--------|\n| 01  | one |\n\n## Notes\n";
+        let updated = update_readme(readme, 2018, 1, "Chronal Calibration")
+            .unwrap()
+            .unwrap();
+        let lines: Vec<&str> = updated.lines().collect();
+        assert_eq!(&lines[6..10], ["| 01  | one |", "", "### 2018", ""]);
+        assert_eq!(&lines[10..12], TABLE_HEADER);
+        assert!(lines[12].starts_with("| 01  | [Day 01: Chronal Calibration]"));
+        assert_eq!(&lines[13..], ["", "## Notes"]);
+
+        // The 2017 row for day 1 is left alone and 2018 gets its own
+        let again = update_readme(&updated, 2018, 1, "Chronal Calibration").unwrap();
+        assert_eq!(again, None);
+    }
+
+    #[test]
+    fn test_parse_title() {
+        let puzzle = "https://adventofcode.com/2017/day/1\n\n--- Day 1: Inverse Captcha ---\n";
+        assert_eq!(parse_title(puzzle), Some("Inverse Captcha".to_string()));
+        assert_eq!(parse_title("nothing here"), None);
+    }
+
+    #[test]
+    fn test_render_templates() {
+        let module = render(DAY_TEMPLATE, 2017, 7, "Recursive Circus");
+        assert!(module.starts_with("// Day 7: Recursive Circus\n"));
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("        7\n"));
+        assert!(!module.contains("{{"));
+
+        let year = render(YEAR_TEMPLATE, 2018, 1, "Chronal Calibration");
+        assert!(year.contains("pub mod day01;"));
+        assert!(year.contains("    year: 2018,"));
+        assert!(year.contains("    &day01::Day01,\n];"));
+        assert!(!year.contains("{{"));
+    }
+}
@@ -0,0 +1,67 @@
+// Day {{DAY}}: {{TITLE}}
+
+use crate::solutions::{Answer, Solution};
+use crate::utils::input::ParseError;
+
+pub fn solve_part1(input: &str) -> usize {
+    input.lines().count()
+}
+
+pub fn solve_part2(input: &str) -> usize {
+    input.lines().count()
+}
+
+pub struct Day{{DAY_PADDED}};
+
+impl Solution for Day{{DAY_PADDED}} {
+    fn day(&self) -> u32 {
+        {{DAY}}
+    }
+
+    fn title(&self) -> &'static str {
+        "{{TITLE}}"
+    }
+
+    fn input(&self) -> &'static str {
+        include_str!("input.txt")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "add the examples from puzzle.txt"]
+    fn test_part1_examples() {
+        assert_eq!(solve_part1(""), 0);
+    }
+
+    #[test]
+    #[ignore = "record the answer once it is accepted"]
+    fn test_part1_input() {
+        let input = include_str!("input.txt");
+        assert_eq!(solve_part1(input), 0);
+    }
+
+    #[test]
+    #[ignore = "add the examples from puzzle.txt"]
+    fn test_part2_examples() {
+        assert_eq!(solve_part2(""), 0);
+    }
+
+    #[test]
+    #[ignore = "record the answer once it is accepted"]
+    fn test_part2_input() {
+        let input = include_str!("input.txt");
+        assert_eq!(solve_part2(input), 0);
+    }
+}
@@ -0,0 +1,17 @@
+// Advent of Code {{YEAR}} - declares the days and their registry
+
+use crate::solutions::{Solution, Year};
+
+pub mod {{DAY_MODULE}};
+
+/// This year's solutions and embedded answers manifest
+pub static YEAR: Year = Year {
+    year: {{YEAR}},
+    solutions: SOLUTIONS,
+    answers: include_str!("answers.json"),
+};
+
+/// Registry of all implemented solutions, ordered by day
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &{{DAY_MODULE}}::Day{{DAY_PADDED}},
+];
@@ -3,6 +3,10 @@ use clap::Parser;
 use reqwest::blocking::Client;
 use reqwest::header::{CONTENT_TYPE, COOKIE, USER_AGENT};
 use scraper::{Html, Selector};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
 use std::time::Duration;
 
 #[derive(Parser, Debug)]
@@ -17,7 +21,7 @@ struct Args {
     #[arg(help = "Answer to submit")]
     answer: String,
 
-    #[arg(short, long, default_value = "2017", help = "Year")]
+    #[arg(short, long, default_value_t = aoc_layout::DEFAULT_YEAR, help = "Year")]
     year: u16,
 
     #[arg(
@@ -25,6 +29,12 @@ struct Args {
         help = "Session cookie value (or set AOC_SESSION_COOKIE env var)"
     )]
     session: Option<String>,
+
+    #[arg(
+        long,
+        help = "Don't record an accepted answer in the year's answers.json"
+    )]
+    no_record: bool,
 }
 
 fn main() -> Result<()> {
@@ -59,9 +69,56 @@ fn main() -> Result<()> {
         &args.answer,
     )?;
 
+    if !args.no_record {
+        let manifest = aoc_layout::answers_path(&aoc_layout::repo_root(), args.year);
+        record_answer(&manifest, args.day, args.part, &args.answer)?;
+        println!("Recorded answer in {}", manifest.display());
+    }
+
+    Ok(())
+}
+
+type Manifest = BTreeMap<u32, BTreeMap<String, String>>;
+
+/// Add an accepted answer to the year's answers manifest, creating it if needed
+fn record_answer(path: &Path, day: u8, part: u8, answer: &str) -> Result<()> {
+    let mut days: Manifest = match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text)
+            .with_context(|| format!("Invalid answers manifest {}", path.display()))?,
+        Err(e) if e.kind() == ErrorKind::NotFound => BTreeMap::new(),
+        Err(e) => return Err(e.into()),
+    };
+    days.entry(day.into())
+        .or_default()
+        .insert(format!("part{}", part), answer.to_string());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format_manifest(&days))?;
     Ok(())
 }
 
+/// Write the manifest with one day per line, matching the checked-in files
+fn format_manifest(days: &Manifest) -> String {
+    if days.is_empty() {
+        return "{}\n".to_string();
+    }
+    let lines: Vec<String> = days
+        .iter()
+        .map(|(day, parts)| {
+            let fields: Vec<String> = parts
+                .iter()
+                .map(|(key, value)| {
+                    format!("\"{}\": {}", key, serde_json::Value::from(value.as_str()))
+                })
+                .collect();
+            format!("  \"{}\": {{ {} }}", day, fields.join(", "))
+        })
+        .collect();
+    format!("{{\n{}\n}}\n", lines.join(",\n"))
+}
+
 fn submit_answer(
     client: &Client,
     session: &str,
@@ -133,3 +190,28 @@ fn submit_answer(
 
     Err(anyhow!("Unexpected response format from AoC"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_manifest_round_trips() {
+        let text = include_str!("../../../src/solutions/y2017/answers.json");
+        let days: Manifest = serde_json::from_str(text).unwrap();
+        assert_eq!(format_manifest(&days), text);
+    }
+
+    #[test]
+    fn test_record_answer() {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-test-{}", std::process::id()));
+        let path = dir.join("y2099").join("answers.json");
+        record_answer(&path, 3, 1, "42").unwrap();
+        record_answer(&path, 1, 2, "say \"hi\"").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "{\n  \"1\": { \"part2\": \"say \\\"hi\\\"\" },\n  \"3\": { \"part1\": \"42\" }\n}\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}